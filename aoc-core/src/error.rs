@@ -0,0 +1,131 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Malformed puzzle input, or input that parses but has no answer (no path,
+/// a cycle in the rules, an answer too large...), located at a 1-indexed
+/// line and column (line 0 for errors outside of the input, like an invalid
+/// parameter or a failure of the solver as a whole).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub msg: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, msg: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            msg: msg.into(),
+        }
+    }
+    /// Error located at the start of `fragment`, which must be a slice of `input`.
+    pub fn at(input: &str, fragment: &str, msg: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or_default();
+        let before = &input.as_bytes()[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|&c| c == b'\n')
+            .map_or(0, |i| i + 1);
+        let line = before.iter().filter(|&&c| c == b'\n').count() + 1;
+        let column = String::from_utf8_lossy(&before[line_start..])
+            .chars()
+            .count()
+            + 1;
+        Self::new(line, column, msg)
+    }
+    pub fn unlocated(msg: impl Into<String>) -> Self {
+        Self::new(0, 0, msg)
+    }
+    /// Error located right after the last non blank character of `input`,
+    /// for something missing.
+    pub fn eof(input: &str, msg: impl Into<String>) -> Self {
+        Self::at(input, &input[input.trim_end().len()..], msg)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}", self.msg);
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.msg
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `token`, a slice of `input`, with its [`FromStr`] implementation.
+pub fn parse_token<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError>
+where
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|e| ParseError::at(input, token, format!("cannot parse `{token}`: {e}")))
+}
+
+/// Split `s`, a slice of `input`, around the first occurrence of `sep`, or
+/// fail at the start of `s`.
+pub fn split_token<'a>(
+    input: &str,
+    s: &'a str,
+    sep: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep).ok_or_else(|| {
+        ParseError::at(
+            input,
+            s,
+            format!(
+                "expected `{}` in `{}`",
+                sep.escape_debug(),
+                s.escape_debug()
+            ),
+        )
+    })
+}
+
+/// Remove `prefix` from `s`, a slice of `input`.
+pub fn strip_token<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        ParseError::at(
+            input,
+            s,
+            format!(
+                "expected `{}` at start of `{}`",
+                prefix.escape_debug(),
+                s.escape_debug()
+            ),
+        )
+    })
+}
+
+#[test]
+fn test_location() {
+    let input = "12 4\n7 x9\n";
+    let token = &input[7..9];
+    assert_eq!(
+        parse_token::<u32>(input, token),
+        Err(ParseError::new(
+            2,
+            3,
+            "cannot parse `x9`: invalid digit found in string"
+        ))
+    );
+    assert_eq!(ParseError::eof(input, "").line, 2);
+    assert_eq!(ParseError::eof(input, "").column, 5);
+    assert_eq!(ParseError::at(input, "elsewhere", "").line, 1);
+    assert_eq!(split_token(input, &input[..4], " "), Ok(("12", "4")));
+    assert_eq!(strip_token(input, &input[5..], "7 ").map(str::len), Ok(3));
+}
@@ -0,0 +1,36 @@
+//! Interface every day implements so the `aoc` runner can drive it.
+
+use std::fmt::Display;
+
+mod error;
+mod params;
+
+pub use error::{parse_token, split_token, strip_token, ParseError};
+pub use params::Params;
+
+/// Solver of one day of the calendar.
+pub trait Solution {
+    /// Day of the calendar, from 1 to 25.
+    const DAY: u8;
+    /// Model handed to both parts, it may borrow from the puzzle input.
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+    /// Names of the [`Params`] understood by [`Solution::parse_with`].
+    const PARAMS: &'static [&'static str] = &[];
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    /// Same as [`Solution::parse`] for the days without parameters.
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+    /// Fails with a [`ParseError`] too when the input has no answer.
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError>;
+    /// Report on the input printed by `aoc run --explain`, for the days that
+    /// have more to tell than their answers.
+    fn explain(input: &Self::Input<'_>) -> Option<String> {
+        let _ = input;
+        None
+    }
+}
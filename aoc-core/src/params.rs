@@ -0,0 +1,66 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::ParseError;
+
+/// `KEY=VALUE` settings given to a day on top of its puzzle input, such as
+/// the size of a map that the input does not tell.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(Vec<(String, String)>);
+
+impl Params {
+    /// Set `key` to `value`, replacing any previous value.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let (key, value) = (key.into(), value.into());
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.0.push((key, value)),
+        }
+    }
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(k, _)| k.as_str())
+    }
+    /// Value of `key` parsed with its [`FromStr`] implementation, if set.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        let Some((_, value)) = self.0.iter().find(|(k, _)| k == key) else {
+            return Ok(None);
+        };
+        value
+            .parse()
+            .map(Some)
+            .map_err(|e| ParseError::unlocated(format!("invalid parameter `{key}={value}`: {e}")))
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+    /// Comma separated `KEY=VALUE` pairs.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in s.split(',').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected `KEY=VALUE`, got `{pair}`"))?;
+            params.insert(key, value);
+        }
+        Ok(params)
+    }
+}
+
+#[test]
+fn test_params() {
+    let params: Params = "width=11,height=7,width=12".parse().unwrap();
+    assert_eq!(params.keys().collect::<Vec<_>>(), ["width", "height"]);
+    assert_eq!(params.get::<u8>("width"), Ok(Some(12)));
+    assert_eq!(params.get::<u8>("size"), Ok(None));
+    assert!(params.get::<u8>("height").is_ok());
+    assert_eq!(
+        "size=x".parse::<Params>().unwrap().get::<u8>("size"),
+        Err(ParseError::unlocated(
+            "invalid parameter `size=x`: invalid digit found in string"
+        ))
+    );
+    assert!("size".parse::<Params>().is_err());
+}
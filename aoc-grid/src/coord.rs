@@ -0,0 +1,186 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign},
+};
+
+/// Signed integer usable as a [`Coord`] component.
+pub trait Num:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const NEG_ONE: Self;
+    fn from_isize(n: isize) -> Self;
+    fn to_isize(self) -> isize;
+}
+
+macro_rules! impl_num {
+    ($($t:ty),*) => {
+        $(
+            impl Num for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const NEG_ONE: Self = -1;
+                #[inline(always)]
+                fn from_isize(n: isize) -> Self {
+                    n as $t
+                }
+                #[inline(always)]
+                fn to_isize(self) -> isize {
+                    self as isize
+                }
+            }
+        )*
+    };
+}
+impl_num!(i8, i16, i32, i64, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub struct Coord<N: Num = isize>(pub [N; 2]);
+
+impl<N: Num> Coord<N> {
+    pub const RIGHT: Self = Coord([N::ONE, N::ZERO]);
+    pub const DOWN: Self = Coord([N::ZERO, N::ONE]);
+    pub const LEFT: Self = Coord([N::NEG_ONE, N::ZERO]);
+    pub const UP: Self = Coord([N::ZERO, N::NEG_ONE]);
+    /// 4-connected directions, clockwise starting from [`Coord::RIGHT`].
+    pub const DIRECTIONS: [Self; 4] = [Self::RIGHT, Self::DOWN, Self::LEFT, Self::UP];
+    /// Diagonal directions, clockwise starting from down-right.
+    pub const DIAGONALS: [Self; 4] = [
+        Coord([N::ONE, N::ONE]),
+        Coord([N::NEG_ONE, N::ONE]),
+        Coord([N::NEG_ONE, N::NEG_ONE]),
+        Coord([N::ONE, N::NEG_ONE]),
+    ];
+    /// 8-connected directions, clockwise starting from [`Coord::RIGHT`].
+    pub const DIRECTIONS8: [Self; 8] = [
+        Self::RIGHT,
+        Self::DIAGONALS[0],
+        Self::DOWN,
+        Self::DIAGONALS[1],
+        Self::LEFT,
+        Self::DIAGONALS[2],
+        Self::UP,
+        Self::DIAGONALS[3],
+    ];
+
+    pub const fn new(x: N, y: N) -> Self {
+        Coord([x, y])
+    }
+    pub fn x(&self) -> N {
+        self.0[0]
+    }
+    pub fn y(&self) -> N {
+        self.0[1]
+    }
+    /// Quarter turn clockwise (with `y` going down).
+    pub fn rotate_right(&self) -> Self {
+        let [x, y] = self.0;
+        Coord([-y, x])
+    }
+    /// Quarter turn counter-clockwise (with `y` going down).
+    pub fn rotate_left(&self) -> Self {
+        let [x, y] = self.0;
+        Coord([y, -x])
+    }
+    pub fn manhattan(&self, other: Self) -> usize {
+        let [x1, y1] = self.0.map(Num::to_isize);
+        let [x2, y2] = other.0.map(Num::to_isize);
+        x1.abs_diff(x2) + y1.abs_diff(y2)
+    }
+    pub fn cast<M: Num>(self) -> Coord<M> {
+        Coord(self.0.map(|x| M::from_isize(x.to_isize())))
+    }
+}
+
+impl<N: Num> Add for Coord<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Coord([self.0[0] + rhs.0[0], self.0[1] + rhs.0[1]])
+    }
+}
+impl<N: Num> Add<N> for Coord<N> {
+    type Output = Self;
+    fn add(self, rhs: N) -> Self::Output {
+        Coord([self.0[0] + rhs, self.0[1] + rhs])
+    }
+}
+impl<N: Num> AddAssign for Coord<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<N: Num> Sub for Coord<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coord([self.0[0] - rhs.0[0], self.0[1] - rhs.0[1]])
+    }
+}
+impl<N: Num> SubAssign for Coord<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<N: Num> Neg for Coord<N> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Coord([-self.0[0], -self.0[1]])
+    }
+}
+impl<N: Num> Mul<N> for Coord<N> {
+    type Output = Self;
+    fn mul(self, rhs: N) -> Self::Output {
+        Coord([self.0[0] * rhs, self.0[1] * rhs])
+    }
+}
+impl<N: Num> Div<N> for Coord<N> {
+    type Output = Self;
+    fn div(self, rhs: N) -> Self::Output {
+        Coord([self.0[0] / rhs, self.0[1] / rhs])
+    }
+}
+impl<N: Num> Div for Coord<N> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        Coord([self.0[0] / rhs.0[0], self.0[1] / rhs.0[1]])
+    }
+}
+impl<N: Num> Rem for Coord<N> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        Coord([self.0[0] % rhs.0[0], self.0[1] % rhs.0[1]])
+    }
+}
+
+#[test]
+fn test_rotations() {
+    let mut dir = Coord::<i8>::UP;
+    for expected in [Coord::RIGHT, Coord::DOWN, Coord::LEFT, Coord::UP] {
+        dir = dir.rotate_right();
+        assert_eq!(dir, expected);
+        assert_eq!(dir.rotate_left().rotate_right(), dir);
+    }
+    assert_eq!(Coord::<i32>::RIGHT.rotate_left(), Coord::UP);
+}
+#[test]
+fn test_ops() {
+    let c = Coord::new(3isize, -2);
+    assert_eq!(c + Coord::new(1, 1), Coord::new(4, -1));
+    assert_eq!(c - c, Coord::default());
+    assert_eq!(-c, Coord::new(-3, 2));
+    assert_eq!(c * 2, Coord::new(6, -4));
+    assert_eq!(c.manhattan(Coord::new(0, 0)), 5);
+    assert_eq!(c.cast::<i8>(), Coord::new(3i8, -2));
+}
@@ -0,0 +1,219 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::ParseError;
+
+use crate::{Coord, Num};
+
+/// Rectangular grid of `nx` columns and `ny` rows stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    nx: usize,
+    ny: usize,
+    buf: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(nx: usize, ny: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            nx,
+            ny,
+            buf: vec![fill; nx * ny],
+        }
+    }
+    pub fn from_vec(nx: usize, ny: usize, buf: Vec<T>) -> Self {
+        assert_eq!(buf.len(), nx * ny, "grid of {nx}x{ny} cells");
+        Grid { nx, ny, buf }
+    }
+    /// Build a grid from the lines of `input`, mapping every byte with `f`
+    /// which returns `None` on unexpected characters.
+    pub fn parse(input: &str, mut f: impl FnMut(u8) -> Option<T>) -> Result<Self, ParseError> {
+        let lines = input.trim().lines().map(str::trim_end);
+        let nx = lines.clone().next().map_or(0, str::len);
+        let mut buf = Vec::new();
+        let mut ny = 0;
+        if nx == 0 {
+            return Err(ParseError::eof(input, "empty grid"));
+        }
+        for line in lines {
+            if line.len() != nx {
+                let msg = format!("line of {} cells in a grid of width {nx}", line.len());
+                return Err(ParseError::at(input, line, msg));
+            }
+            for (i, c) in line.bytes().enumerate() {
+                let cell = f(c).ok_or_else(|| {
+                    let msg = format!("unexpected character `{}`", c as char);
+                    ParseError::at(input, &line[i..], msg)
+                })?;
+                buf.push(cell);
+            }
+            ny += 1;
+        }
+        Ok(Self::from_vec(nx, ny, buf))
+    }
+    pub fn nx(&self) -> usize {
+        self.nx
+    }
+    pub fn ny(&self) -> usize {
+        self.ny
+    }
+    pub fn dims(&self) -> [usize; 2] {
+        [self.nx, self.ny]
+    }
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+    #[inline(always)]
+    pub fn contains<N: Num>(&self, coord: Coord<N>) -> bool {
+        inside(self.dims(), coord)
+    }
+    /// Index of `coord` in the row by row buffer if it is inside the grid.
+    #[inline(always)]
+    pub fn idx<N: Num>(&self, coord: Coord<N>) -> Option<usize> {
+        self.contains(coord).then(|| {
+            let [x, y] = coord.0.map(|x| x.to_isize() as usize);
+            x + self.nx * y
+        })
+    }
+    #[inline(always)]
+    pub fn pos<N: Num>(&self, idx: usize) -> Coord<N> {
+        debug_assert!(idx < self.buf.len());
+        Coord([idx % self.nx, idx / self.nx].map(|x| N::from_isize(x as isize)))
+    }
+    pub fn get<N: Num>(&self, coord: Coord<N>) -> Option<&T> {
+        self.idx(coord).map(|i| &self.buf[i])
+    }
+    pub fn get_mut<N: Num>(&mut self, coord: Coord<N>) -> Option<&mut T> {
+        self.idx(coord).map(|i| &mut self.buf[i])
+    }
+    /// Write `value` at `coord`, returns false if `coord` is outside.
+    pub fn set<N: Num>(&mut self, coord: Coord<N>, value: T) -> bool {
+        self.get_mut(coord).map(|x| *x = value).is_some()
+    }
+    /// Every coordinate of the grid, row by row.
+    pub fn coords<N: Num>(&self) -> impl Iterator<Item = Coord<N>> {
+        let [nx, ny] = self.dims().map(|x| x as isize);
+        (0..ny).flat_map(move |y| (0..nx).map(move |x| Coord([x, y].map(N::from_isize))))
+    }
+    /// Coordinates on the edge of the grid, row by row.
+    pub fn border<N: Num>(&self) -> impl Iterator<Item = Coord<N>> {
+        let [nx, ny] = self.dims().map(|x| x as isize);
+        self.coords().filter(move |c: &Coord<N>| {
+            let [x, y] = c.0.map(Num::to_isize);
+            x == 0 || y == 0 || x == nx - 1 || y == ny - 1
+        })
+    }
+    /// 4-connected neighbors of `coord` that are inside the grid.
+    pub fn neighbors4<N: Num>(&self, coord: Coord<N>) -> impl Iterator<Item = Coord<N>> {
+        let dims = self.dims();
+        Coord::DIRECTIONS
+            .into_iter()
+            .map(move |dir| coord + dir)
+            .filter(move |&nei| inside(dims, nei))
+    }
+    /// 8-connected neighbors of `coord` that are inside the grid.
+    pub fn neighbors8<N: Num>(&self, coord: Coord<N>) -> impl Iterator<Item = Coord<N>> {
+        let dims = self.dims();
+        Coord::DIRECTIONS8
+            .into_iter()
+            .map(move |dir| coord + dir)
+            .filter(move |&nei| inside(dims, nei))
+    }
+    /// Coordinate of the first cell (row by row) matching `pred`.
+    pub fn position<N: Num>(&self, pred: impl FnMut(&T) -> bool) -> Option<Coord<N>> {
+        self.buf.iter().position(pred).map(|i| self.pos(i))
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.buf.iter()
+    }
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.buf.iter_mut()
+    }
+    pub fn into_vec(self) -> Vec<T> {
+        self.buf
+    }
+}
+
+#[inline(always)]
+fn inside<N: Num>([nx, ny]: [usize; 2], coord: Coord<N>) -> bool {
+    let [x, y] = coord.0.map(Num::to_isize);
+    (0..nx as isize).contains(&x) && (0..ny as isize).contains(&y)
+}
+
+impl<T, N: Num> Index<Coord<N>> for Grid<T> {
+    type Output = T;
+    fn index(&self, coord: Coord<N>) -> &Self::Output {
+        let i = self
+            .idx(coord)
+            .unwrap_or_else(|| panic!("{coord:?} outside of {}x{} grid", self.nx, self.ny));
+        &self.buf[i]
+    }
+}
+impl<T, N: Num> IndexMut<Coord<N>> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord<N>) -> &mut Self::Output {
+        let i = self
+            .idx(coord)
+            .unwrap_or_else(|| panic!("{coord:?} outside of {}x{} grid", self.nx, self.ny));
+        &mut self.buf[i]
+    }
+}
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.buf[idx]
+    }
+}
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.buf[idx]
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.buf.chunks(self.nx) {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse() {
+    let g = Grid::parse("\n#..\n.#E\n", Some).unwrap();
+    assert_eq!(g.dims(), [3, 2]);
+    assert_eq!(g[Coord::new(1isize, 1)], b'#');
+    assert_eq!(g.position(|&c| c == b'E'), Some(Coord::new(2i32, 1)));
+    assert_eq!(g.get(Coord::new(3i8, 0)), None);
+    assert_eq!(g.to_string(), "#..\n.#E\n");
+    let digit = |c: u8| c.is_ascii_digit().then(|| c - b'0');
+    assert_eq!(
+        Grid::parse("12\n3x", digit),
+        Err(ParseError::new(2, 2, "unexpected character `x`"))
+    );
+    assert_eq!(
+        Grid::parse("12\n345", digit),
+        Err(ParseError::new(
+            2,
+            1,
+            "line of 3 cells in a grid of width 2"
+        ))
+    );
+}
+#[test]
+fn test_neighbors() {
+    let g = Grid::new(3, 2, 0u8);
+    assert_eq!(g.neighbors4(Coord::new(0i16, 0)).count(), 2);
+    assert_eq!(g.neighbors8(Coord::new(1i16, 0)).count(), 5);
+    assert_eq!(g.coords::<i16>().count(), 6);
+    assert_eq!(Grid::new(4, 3, 0u8).border::<i16>().count(), 10);
+    assert_eq!(g.pos::<i16>(4), Coord::new(1, 1));
+}
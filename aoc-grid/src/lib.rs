@@ -0,0 +1,10 @@
+//! Grid and coordinate helpers shared by the days working on 2D maps.
+//!
+//! Coordinates are `[x, y]` with `x` going right and `y` going down, so
+//! [`Coord::UP`] is `[0, -1]` and [`Coord::rotate_right`] turns clockwise.
+
+mod coord;
+mod grid;
+
+pub use coord::{Coord, Num};
+pub use grid::Grid;
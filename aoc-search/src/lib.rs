@@ -0,0 +1,14 @@
+//! Shortest path searches shared by the days exploring a graph of states.
+//!
+//! States are numbered by [`State::index`] below the number of states given
+//! to a search, which keeps distances and predecessors in flat arrays. The
+//! [`Search`] outcome keeps every best predecessor of each state: the
+//! predecessor DAG gives one or all best paths, or how many there are. Those
+//! of a [`FixedDegree`] graph fit in a bit mask per state.
+
+mod preds;
+mod queue;
+mod search;
+
+pub use preds::{FixedDegree, Lists, Masks, Preds};
+pub use search::{astar, bfs, bfs_cost, dijkstra, dijkstra_cost, dijkstra_fixed, Search, State};
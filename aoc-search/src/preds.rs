@@ -0,0 +1,124 @@
+use crate::State;
+
+/// Best predecessors of the states of a [`Search`](crate::Search).
+pub trait Preds<S> {
+    /// `from` becomes the only best predecessor of `to`.
+    fn set(&mut self, to: S, from: S);
+    /// `from` is one more best predecessor of `to`.
+    fn add(&mut self, to: S, from: S);
+    /// Best predecessors of `state`, none for a start or a state not reached.
+    fn iter<'a>(&'a self, state: S) -> impl Iterator<Item = S> + 'a
+    where
+        S: 'a;
+}
+
+/// No predecessor, or end of a list of predecessors.
+const NONE: u32 = u32::MAX;
+/// A single predecessor.
+const ONE: u32 = u32::MAX - 1;
+
+/// Predecessors of any graph, as a list per state.
+#[derive(Debug, Clone)]
+pub struct Lists<S> {
+    /// Last best predecessor found for each state with one, so that most
+    /// states, which have a single one, need nothing else.
+    first: Vec<S>,
+    /// Other best predecessors of each state: the first one in `edges`,
+    /// [`ONE`] when there are none, [`NONE`] for a state without predecessor.
+    more: Vec<u32>,
+    /// Best predecessors, each with the next one of the same state.
+    edges: Vec<(S, u32)>,
+}
+
+impl<S: State> Lists<S> {
+    /// Lists for `n_states`, `fill` being any state to fill the first
+    /// predecessors with until they are found (none without any state).
+    pub(crate) fn new(n_states: usize, fill: Option<S>) -> Self {
+        Lists {
+            first: fill.map_or_else(Vec::new, |fill| vec![fill; n_states]),
+            more: vec![NONE; n_states],
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<S: State> Preds<S> for Lists<S> {
+    #[inline(always)]
+    fn set(&mut self, to: S, from: S) {
+        let i = to.index();
+        self.first[i] = from;
+        self.more[i] = ONE;
+    }
+    #[inline(always)]
+    fn add(&mut self, to: S, from: S) {
+        let i = to.index();
+        // the previous last one joins the others
+        let next = if self.more[i] == ONE {
+            NONE
+        } else {
+            self.more[i]
+        };
+        self.more[i] = self.edges.len() as u32;
+        self.edges.push((self.first[i], next));
+        self.first[i] = from;
+    }
+    fn iter<'a>(&'a self, state: S) -> impl Iterator<Item = S> + 'a
+    where
+        S: 'a,
+    {
+        let i = state.index();
+        let first = (self.more[i] != NONE).then(|| self.first[i]);
+        let mut edge = self.more[i];
+        first.into_iter().chain(std::iter::from_fn(move || {
+            let (pred, next) = *self.edges.get(edge as usize)?;
+            edge = next;
+            Some(pred)
+        }))
+    }
+}
+
+/// Graph where each state can only be reached from 8 states or less, known
+/// in advance, like a state on a grid from its neighbors.
+pub trait FixedDegree<S> {
+    /// Number below 8 of `from` among the states `to` can be reached from.
+    fn slot(&self, from: S, to: S) -> u32;
+    /// State numbered `slot` among the states `to` can be reached from.
+    fn pred(&self, to: S, slot: u32) -> S;
+}
+
+/// Predecessors of a [`FixedDegree`] graph, as a byte per state with a bit
+/// per possible predecessor.
+#[derive(Debug, Clone)]
+pub struct Masks<G> {
+    graph: G,
+    masks: Vec<u8>,
+}
+
+impl<G> Masks<G> {
+    pub(crate) fn new(n_states: usize, graph: G) -> Self {
+        Masks {
+            graph,
+            masks: vec![0; n_states],
+        }
+    }
+}
+
+impl<S: State, G: FixedDegree<S>> Preds<S> for Masks<G> {
+    #[inline(always)]
+    fn set(&mut self, to: S, from: S) {
+        self.masks[to.index()] = 1 << self.graph.slot(from, to);
+    }
+    #[inline(always)]
+    fn add(&mut self, to: S, from: S) {
+        self.masks[to.index()] |= 1 << self.graph.slot(from, to);
+    }
+    fn iter<'a>(&'a self, state: S) -> impl Iterator<Item = S> + 'a
+    where
+        S: 'a,
+    {
+        let mask = self.masks[state.index()];
+        (0..8)
+            .filter(move |slot| mask & (1 << slot) != 0)
+            .map(move |slot| self.graph.pred(state, slot))
+    }
+}
@@ -0,0 +1,53 @@
+/// Monotone priority queue: the popped keys never decrease, so entries are
+/// bucketed by the highest bit where their key differs from the last popped
+/// one, and only the first non empty bucket is ever sorted out.
+pub(crate) struct RadixHeap<T> {
+    last: u32,
+    buckets: [Vec<(u32, T)>; 33],
+}
+
+impl<T> RadixHeap<T> {
+    pub(crate) fn new() -> Self {
+        RadixHeap {
+            last: 0,
+            buckets: std::array::from_fn(|_| Vec::new()),
+        }
+    }
+    fn bucket(&self, key: u32) -> usize {
+        (32 - (key ^ self.last).leading_zeros()) as usize
+    }
+    pub(crate) fn push(&mut self, key: u32, item: T) {
+        debug_assert!(key >= self.last);
+        let bucket = self.bucket(key);
+        self.buckets[bucket].push((key, item));
+    }
+    pub(crate) fn pop(&mut self) -> Option<(u32, T)> {
+        if self.buckets[0].is_empty() {
+            let i = self.buckets.iter().position(|b| !b.is_empty())?;
+            let mut entries = std::mem::take(&mut self.buckets[i]);
+            self.last = entries.iter().map(|&(key, _)| key).min()?;
+            // every entry goes to a lower bucket
+            for (key, item) in entries.drain(..) {
+                let bucket = self.bucket(key);
+                self.buckets[bucket].push((key, item));
+            }
+            self.buckets[i] = entries;
+        }
+        self.buckets[0].pop()
+    }
+}
+
+#[test]
+fn test_radix_heap() {
+    let mut heap = RadixHeap::new();
+    for key in [5, 3, 8, 3, 1000, 0] {
+        heap.push(key, ());
+    }
+    let mut popped = vec![heap.pop().unwrap().0];
+    heap.push(1, ());
+    heap.push(7, ());
+    while let Some((key, ())) = heap.pop() {
+        popped.push(key);
+    }
+    assert_eq!(popped, [0, 1, 3, 3, 5, 7, 8, 1000]);
+}
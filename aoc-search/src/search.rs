@@ -0,0 +1,491 @@
+use std::collections::VecDeque;
+
+use crate::{
+    preds::{FixedDegree, Lists, Masks, Preds},
+    queue::RadixHeap,
+};
+
+/// State of a search, numbered below the number of states of the search.
+pub trait State: Copy + Eq {
+    fn index(&self) -> usize;
+}
+
+impl State for usize {
+    fn index(&self) -> usize {
+        *self
+    }
+}
+
+/// Lowest costs from the starts of a search, with every best predecessor of
+/// the reached states kept in `P`.
+#[derive(Debug, Clone)]
+pub struct Search<S, P = Lists<S>> {
+    starts: Vec<S>,
+    /// Lowest cost of each state, `u32::MAX` when not reached.
+    dist: Vec<u32>,
+    preds: P,
+    /// States expanded by the search, in order.
+    visited: Vec<S>,
+    /// Ends reached with the lowest cost.
+    ends: Vec<S>,
+}
+
+impl<S: State, P: Preds<S>> Search<S, P> {
+    /// Search from `starts`, keeping the predecessors in the store built by
+    /// `preds` from any start.
+    fn new(
+        n_states: usize,
+        starts: impl IntoIterator<Item = S>,
+        preds: impl FnOnce(Option<S>) -> P,
+    ) -> Self {
+        let mut dist = vec![u32::MAX; n_states];
+        let mut unique = Vec::new();
+        for start in starts {
+            if dist[start.index()] != 0 {
+                dist[start.index()] = 0;
+                unique.push(start);
+            }
+        }
+        Search {
+            preds: preds(unique.first().copied()),
+            starts: unique,
+            dist,
+            visited: Vec::with_capacity(n_states),
+            ends: Vec::new(),
+        }
+    }
+    /// Reach `to` from `from` with `cost`, whether it is better than before.
+    #[inline(always)]
+    fn relax(&mut self, from: S, to: S, cost: u32) -> bool {
+        let i = to.index();
+        if cost > self.dist[i] || self.is_start(to) {
+            return false;
+        }
+        if cost < self.dist[i] {
+            self.dist[i] = cost;
+            self.preds.set(to, from);
+            return true;
+        }
+        self.preds.add(to, from);
+        false
+    }
+    /// Starts are the only states reached without predecessor.
+    fn is_start(&self, state: S) -> bool {
+        self.dist[state.index()] == 0 && self.preds.iter(state).next().is_none()
+    }
+    /// Lowest cost of `state`, if reached.
+    pub fn dist(&self, state: S) -> Option<u32> {
+        Some(self.dist[state.index()]).filter(|&d| d != u32::MAX)
+    }
+    /// Lowest cost of an end, if any was reached.
+    pub fn best(&self) -> Option<u32> {
+        self.ends.first().map(|&end| self.dist[end.index()])
+    }
+    /// Ends reached with the lowest cost, in the order they were found.
+    pub fn ends(&self) -> &[S] {
+        &self.ends
+    }
+    /// States expanded by the search, by increasing cost (or estimate for
+    /// [`astar`]).
+    pub fn visited(&self) -> &[S] {
+        &self.visited
+    }
+    /// Every state from which `state` is reached with its lowest cost.
+    pub fn preds(&self, state: S) -> impl Iterator<Item = S> + '_ {
+        self.preds.iter(state)
+    }
+    /// One of the best paths from a start to `end`, if reached.
+    pub fn path(&self, end: S) -> Option<Vec<S>> {
+        self.dist(end)?;
+        let mut path = vec![end];
+        let mut cur = end;
+        while !self.is_start(cur) {
+            cur = self.preds(cur).next()?;
+            path.push(cur);
+        }
+        path.reverse();
+        Some(path)
+    }
+    /// Every best path from a start to `end`, found one after the other.
+    pub fn paths(&self, end: S) -> impl Iterator<Item = Vec<S>> + '_ {
+        let mut single = self.is_start(end).then(|| vec![end]);
+        // depth first walk of the predecessors from the end back to a start,
+        // with the predecessors left to try from each state of `path`
+        let mut path = Vec::new();
+        let mut on_path = vec![false; self.dist.len()];
+        let mut stack = Vec::new();
+        if self.dist(end).is_some() && single.is_none() {
+            path.push(end);
+            on_path[end.index()] = true;
+            stack.push(self.preds(end));
+        }
+        std::iter::from_fn(move || {
+            if let Some(single) = single.take() {
+                return Some(single);
+            }
+            loop {
+                // a loop is only possible with moves costing nothing
+                let pred = stack.last_mut()?.find(|pred| !on_path[pred.index()]);
+                let Some(pred) = pred else {
+                    stack.pop();
+                    let state = path.pop()?;
+                    on_path[state.index()] = false;
+                    continue;
+                };
+                if self.is_start(pred) {
+                    let mut found = path.clone();
+                    found.push(pred);
+                    found.reverse();
+                    return Some(found);
+                }
+                path.push(pred);
+                on_path[pred.index()] = true;
+                stack.push(self.preds(pred));
+            }
+        })
+    }
+    /// Whether each state, by index, is on a best path to one of `ends`.
+    pub fn on_best_paths(&self, ends: impl IntoIterator<Item = S>) -> Vec<bool> {
+        let mut on_path = vec![false; self.dist.len()];
+        let mut stack = Vec::new();
+        for end in ends.into_iter().filter(|&end| self.dist(end).is_some()) {
+            on_path[end.index()] = true;
+            stack.push(end);
+        }
+        while let Some(state) = stack.pop() {
+            for pred in self.preds(state) {
+                if !on_path[pred.index()] {
+                    on_path[pred.index()] = true;
+                    stack.push(pred);
+                }
+            }
+        }
+        on_path
+    }
+    /// Number of best paths from a start to each visited state, by index,
+    /// for moves all costing something.
+    pub fn count_paths(&self) -> Vec<u64> {
+        let mut counts = vec![0; self.dist.len()];
+        let mut states = self.visited.clone();
+        states.sort_by_key(|s| self.dist[s.index()]);
+        for state in states {
+            counts[state.index()] = if self.is_start(state) {
+                1
+            } else {
+                self.preds(state).map(|pred| counts[pred.index()]).sum()
+            };
+        }
+        counts
+    }
+}
+
+/// Breadth first search from `starts` along moves costing 1, until the
+/// states as close as the first end found are all visited.
+pub fn bfs<S, I>(
+    n_states: usize,
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(S) -> I,
+    mut is_end: impl FnMut(S) -> bool,
+) -> Search<S>
+where
+    S: State,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(n_states, starts, |fill| Lists::new(n_states, fill));
+    let mut queue = search.starts.iter().copied().collect::<VecDeque<_>>();
+    while let Some(cur) = queue.pop_front() {
+        let cost = search.dist[cur.index()];
+        if search.best().is_some_and(|best| cost > best) {
+            break;
+        }
+        search.visited.push(cur);
+        if is_end(cur) {
+            search.ends.push(cur);
+            continue;
+        }
+        for next in neighbors(cur) {
+            if search.relax(cur, next, cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Lowest cost of an end, as [`bfs`] without keeping any predecessor, for
+/// when nothing else matters.
+pub fn bfs_cost<S, I>(
+    n_states: usize,
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(S) -> I,
+    mut is_end: impl FnMut(S) -> bool,
+) -> Option<u32>
+where
+    S: State,
+    I: IntoIterator<Item = S>,
+{
+    let mut dist = vec![u32::MAX; n_states];
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if is_end(start) {
+            return Some(0);
+        }
+        dist[start.index()] = 0;
+        queue.push_back(start);
+    }
+    while let Some(cur) = queue.pop_front() {
+        let cost = dist[cur.index()] + 1;
+        for next in neighbors(cur) {
+            if dist[next.index()] == u32::MAX {
+                // the first cost found is the lowest in a breadth first search
+                if is_end(next) {
+                    return Some(cost);
+                }
+                dist[next.index()] = cost;
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra search from `starts` along the moves and costs given by
+/// `neighbors`, until the states as cheap as the first end found are all
+/// visited.
+pub fn dijkstra<S, I>(
+    n_states: usize,
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(S) -> I,
+    is_end: impl FnMut(S) -> bool,
+) -> Search<S>
+where
+    S: State,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    let search = Search::new(n_states, starts, |fill| Lists::new(n_states, fill));
+    run_dijkstra(search, neighbors, is_end)
+}
+
+/// [`dijkstra`] on a [`FixedDegree`] `graph`, whose predecessors are kept as
+/// bit masks: lighter than the lists of predecessors of any graph.
+pub fn dijkstra_fixed<S, G, I>(
+    n_states: usize,
+    graph: G,
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(S) -> I,
+    is_end: impl FnMut(S) -> bool,
+) -> Search<S, Masks<G>>
+where
+    S: State,
+    G: FixedDegree<S>,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    let search = Search::new(n_states, starts, |_| Masks::new(n_states, graph));
+    run_dijkstra(search, neighbors, is_end)
+}
+
+fn run_dijkstra<S, P, I>(
+    mut search: Search<S, P>,
+    mut neighbors: impl FnMut(S) -> I,
+    mut is_end: impl FnMut(S) -> bool,
+) -> Search<S, P>
+where
+    S: State,
+    P: Preds<S>,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    let mut queue = RadixHeap::new();
+    for &start in &search.starts {
+        queue.push(0, start);
+    }
+    while let Some((cost, cur)) = queue.pop() {
+        if search.best().is_some_and(|best| cost > best) {
+            break;
+        }
+        if cost > search.dist[cur.index()] {
+            // already visited with a lower cost
+            continue;
+        }
+        search.visited.push(cur);
+        if is_end(cur) {
+            search.ends.push(cur);
+            continue;
+        }
+        for (next, step) in neighbors(cur) {
+            let next_cost = cost + step;
+            if search.relax(cur, next, next_cost) {
+                queue.push(next_cost, next);
+            }
+        }
+    }
+    search
+}
+
+/// Lowest cost of an end, as [`dijkstra`] without keeping any predecessor,
+/// for when nothing else matters.
+pub fn dijkstra_cost<S, I>(
+    n_states: usize,
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(S) -> I,
+    mut is_end: impl FnMut(S) -> bool,
+) -> Option<u32>
+where
+    S: State,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    let mut dist = vec![u32::MAX; n_states];
+    let mut queue = RadixHeap::new();
+    for start in starts {
+        dist[start.index()] = 0;
+        queue.push(0, start);
+    }
+    while let Some((cost, cur)) = queue.pop() {
+        if cost > dist[cur.index()] {
+            // already visited with a lower cost
+            continue;
+        }
+        if is_end(cur) {
+            return Some(cost);
+        }
+        for (next, step) in neighbors(cur) {
+            let next_cost = cost + step;
+            if next_cost < dist[next.index()] {
+                dist[next.index()] = next_cost;
+                queue.push(next_cost, next);
+            }
+        }
+    }
+    None
+}
+
+/// A* search: [`dijkstra`] visiting first the states with the lowest cost
+/// plus `heuristic`, which must never overestimate the cost left to an end
+/// nor decrease by more than the cost of a move.
+pub fn astar<S, I>(
+    n_states: usize,
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(S) -> I,
+    mut heuristic: impl FnMut(S) -> u32,
+    mut is_end: impl FnMut(S) -> bool,
+) -> Search<S>
+where
+    S: State,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    let mut search = Search::new(n_states, starts, |fill| Lists::new(n_states, fill));
+    let mut queue = RadixHeap::new();
+    for &start in &search.starts {
+        queue.push(heuristic(start), (0, start));
+    }
+    while let Some((estimate, (cost, cur))) = queue.pop() {
+        if search.best().is_some_and(|best| estimate > best) {
+            break;
+        }
+        if cost > search.dist[cur.index()] {
+            // already visited with a lower cost
+            continue;
+        }
+        search.visited.push(cur);
+        if is_end(cur) {
+            search.ends.push(cur);
+            continue;
+        }
+        for (next, step) in neighbors(cur) {
+            let next_cost = cost + step;
+            if search.relax(cur, next, next_cost) {
+                queue.push(next_cost + heuristic(next), (next_cost, next));
+            }
+        }
+    }
+    search
+}
+
+/// Graph of the tests: `0 -> 1 -> 3` and `0 -> 2 -> 3` cost 2, `3 -> 4` and
+/// `0 -> 4` cost 3.
+#[cfg(test)]
+fn diamond(state: usize) -> Vec<(usize, u32)> {
+    match state {
+        0 => vec![(1, 1), (2, 1), (4, 3)],
+        1 | 2 => vec![(3, 1)],
+        3 => vec![(4, 1)],
+        _ => Vec::new(),
+    }
+}
+
+#[test]
+fn test_dijkstra() {
+    let search = dijkstra(6, [0], diamond, |s| s == 4);
+    assert_eq!(search.best(), Some(3));
+    assert_eq!(search.dist(3), Some(2));
+    assert_eq!(search.dist(5), None);
+    assert_eq!(search.path(4), Some(vec![0, 1, 3, 4]));
+    let mut paths = search.paths(4).collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, [vec![0, 1, 3, 4], vec![0, 2, 3, 4], vec![0, 4]]);
+    assert_eq!(search.count_paths()[4], 3);
+    assert_eq!(search.paths(4).take(2).count(), 2);
+    assert_eq!(search.paths(0).collect::<Vec<_>>(), [vec![0]]);
+    assert_eq!(search.paths(5).count(), 0);
+    assert_eq!(dijkstra_cost(6, [0], diamond, |s| s == 4), Some(3));
+    assert_eq!(dijkstra_cost(6, [0], diamond, |s| s == 5), None);
+    assert_eq!(
+        search.on_best_paths([3]),
+        [true, true, true, true, false, false]
+    );
+}
+/// The states of [`diamond`] are below 8, each one its own slot.
+#[cfg(test)]
+struct Slots;
+
+#[cfg(test)]
+impl FixedDegree<usize> for Slots {
+    fn slot(&self, from: usize, _: usize) -> u32 {
+        from as u32
+    }
+    fn pred(&self, _: usize, slot: u32) -> usize {
+        slot as usize
+    }
+}
+
+#[test]
+fn test_dijkstra_fixed() {
+    let lists = dijkstra(6, [0], diamond, |s| s == 4);
+    let masks = dijkstra_fixed(6, Slots, [0], diamond, |s| s == 4);
+    assert_eq!(masks.best(), Some(3));
+    for state in 0..6 {
+        let mut preds = lists.preds(state).collect::<Vec<_>>();
+        preds.sort();
+        assert_eq!(masks.preds(state).collect::<Vec<_>>(), preds);
+    }
+    assert_eq!(masks.paths(4).count(), 3);
+    assert_eq!(masks.path(0), Some(vec![0]));
+}
+#[test]
+fn test_bfs() {
+    let neighbors = |s: usize| diamond(s).into_iter().map(|(next, _)| next);
+    let search = bfs(6, [0], neighbors, |s| s == 3);
+    assert_eq!(search.best(), Some(2));
+    assert_eq!(search.ends(), [3]);
+    assert_eq!(search.count_paths()[3], 2);
+    assert_eq!(bfs_cost(6, [0], neighbors, |s| s == 3), Some(2));
+    assert_eq!(bfs_cost(6, [3], neighbors, |s| s == 0), None);
+    let search = bfs(6, [1, 2], neighbors, |_| false);
+    assert_eq!(search.visited(), [1, 2, 3, 4]);
+    assert_eq!(search.path(4), Some(vec![2, 3, 4]));
+    assert_eq!(search.paths(4).count(), 2);
+}
+#[test]
+fn test_astar() {
+    // states on a line, the end at 9 and a shortcut from 2 to 8
+    let neighbors = |s: usize| {
+        let mut next = vec![(s + 1, 1)];
+        if s == 2 {
+            next.push((8, 2));
+        }
+        next.into_iter().filter(|&(s, _)| s < 10)
+    };
+    let search = astar(10, [0], neighbors, |s| (9 - s as u32) / 3, |s| s == 9);
+    assert_eq!(search.best(), Some(5));
+    assert_eq!(search.path(9), Some(vec![0, 1, 2, 8, 9]));
+    assert!(!search.visited().contains(&5));
+}
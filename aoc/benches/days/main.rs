@@ -0,0 +1,64 @@
+//! Parse and both parts of every day, on the puzzle input in `inputs/` and on
+//! a larger synthetic one: `cargo bench -p aoc -- day06/part2`.
+
+mod synthetic;
+
+use std::{fs, hint::black_box, path::Path};
+
+use aoc_core::{Params, Solution};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn read_input(day: u8) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../inputs")
+        .join(format!("day{day:02}.txt"));
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()))
+}
+
+/// Bench `S` on its real input and on the larger one built by `large`.
+fn bench_day<S: Solution>(c: &mut Criterion, large: fn(&str) -> String) {
+    let real = read_input(S::DAY);
+    let large = large(&real);
+    let params = Params::default();
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.sample_size(10);
+    for (name, input) in [("real", &real), ("large", &large)] {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse_with(black_box(input), &params).unwrap())
+        });
+        let parsed = S::parse_with(input, &params).unwrap();
+        group.bench_with_input(BenchmarkId::new("part1", name), &parsed, |b, parsed| {
+            b.iter(|| S::part1(black_box(parsed)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &parsed, |b, parsed| {
+            b.iter(|| S::part2(black_box(parsed)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, synthetic::day01);
+    bench_day::<day02::Day02>(c, synthetic::day02);
+    bench_day::<day03::Day03>(c, synthetic::day03);
+    bench_day::<day04::Day04>(c, synthetic::day04);
+    bench_day::<day05::Day05>(c, synthetic::day05);
+    bench_day::<day06::Day06>(c, synthetic::day06);
+    bench_day::<day07::Day07>(c, synthetic::day07);
+    bench_day::<day08::Day08>(c, synthetic::day08);
+    bench_day::<day09::Day09>(c, synthetic::day09);
+    bench_day::<day10::Day10>(c, synthetic::day10);
+    bench_day::<day11::Day11>(c, synthetic::day11);
+    bench_day::<day12::Day12>(c, synthetic::day12);
+    bench_day::<day13::Day13>(c, synthetic::day13);
+    bench_day::<day14::Day14>(c, synthetic::day14);
+    bench_day::<day15::Day15>(c, synthetic::day15);
+    bench_day::<day16::Day16>(c, synthetic::day16);
+    bench_day::<day17::Day17>(c, synthetic::day17);
+    bench_day::<day18::Day18>(c, synthetic::day18);
+    bench_day::<day19::Day19>(c, synthetic::day19);
+    bench_day::<day20::Day20>(c, synthetic::day20);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,247 @@
+//! Larger inputs for every day, built from the real puzzle input when its
+//! pieces can be repeated or tiled, or generated from a fixed seed otherwise.
+
+use std::fmt::Write;
+
+/// Xorshift generator, so that every run benches the same inputs.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+    fn shuffle<T>(&mut self, v: &mut [T]) {
+        for i in (1..v.len()).rev() {
+            v.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Lines of `input` repeated `n` times.
+fn repeat(input: &str, n: usize) -> String {
+    let input = input.trim();
+    let mut out = String::with_capacity((input.len() + 1) * n);
+    for _ in 0..n {
+        out.push_str(input);
+        out.push('\n');
+    }
+    out
+}
+
+/// Map of `grid` tiled `n` times in both directions, the `unique` tiles being
+/// only kept in the bottom left copy.
+fn tile(grid: &str, n: usize, unique: &[char]) -> String {
+    let mut out = String::new();
+    for ty in 0..n {
+        for line in grid.trim().lines() {
+            for tx in 0..n {
+                let first = tx == 0 && ty == n - 1;
+                out.extend(line.chars().map(|c| {
+                    if !first && unique.contains(&c) {
+                        '.'
+                    } else {
+                        c
+                    }
+                }));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Square maze of `size` (odd) tiles closed by walls, carved as a spanning
+/// tree then with some walls knocked down to get several paths, `S` at the
+/// bottom left and `E` at the top right.
+fn maze(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut grid = vec![vec![b'#'; size]; size];
+    let mut stack = vec![(1, 1)];
+    grid[1][1] = b'.';
+    while let Some(&(x, y)) = stack.last() {
+        let mut next = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .map(|(dx, dy)| (x as isize + dx, y as isize + dy))
+            .into_iter()
+            .filter(|&(nx, ny)| nx > 0 && ny > 0 && nx < size as isize && ny < size as isize)
+            .map(|(nx, ny)| (nx as usize, ny as usize))
+            .filter(|&(nx, ny)| ny < size - 1 && nx < size - 1 && grid[ny][nx] == b'#')
+            .collect::<Vec<_>>();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        rng.shuffle(&mut next);
+        let (nx, ny) = next[0];
+        grid[(y + ny) / 2][(x + nx) / 2] = b'.';
+        grid[ny][nx] = b'.';
+        stack.push((nx, ny));
+    }
+    for _ in 0..size * size / 50 {
+        let (x, y) = (1 + rng.below(size - 2), 1 + rng.below(size - 2));
+        if (x + y) % 2 == 1 {
+            grid[y][x] = b'.';
+        }
+    }
+    grid[size - 2][1] = b'S';
+    grid[1][size - 2] = b'E';
+    grid.into_iter()
+        .map(|line| String::from_utf8(line).unwrap() + "\n")
+        .collect()
+}
+
+pub fn day01(_: &str) -> String {
+    let mut rng = Rng::new(1);
+    (0..100_000).fold(String::new(), |mut out, _| {
+        let _ = writeln!(out, "{}   {}", rng.below(100_000), rng.below(100_000));
+        out
+    })
+}
+
+pub fn day02(input: &str) -> String {
+    repeat(input, 20)
+}
+
+pub fn day03(input: &str) -> String {
+    repeat(input, 20)
+}
+
+pub fn day04(input: &str) -> String {
+    tile(input, 3, &[])
+}
+
+/// New updates are shuffled copies of the real ones.
+pub fn day05(input: &str) -> String {
+    let mut rng = Rng::new(5);
+    let (rules, updates) = input.trim().split_once("\n\n").unwrap();
+    let mut out = format!("{rules}\n\n");
+    for _ in 0..10 {
+        for update in updates.lines() {
+            let mut pages = update.split(',').collect::<Vec<_>>();
+            rng.shuffle(&mut pages);
+            out.push_str(&pages.join(","));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// The guard starts in the bottom copy of the lab and walks up through the
+/// other ones.
+pub fn day06(input: &str) -> String {
+    tile(input, 3, &['^'])
+}
+
+pub fn day07(input: &str) -> String {
+    repeat(input, 4)
+}
+
+pub fn day08(input: &str) -> String {
+    tile(input, 3, &[])
+}
+
+/// Copies of the disk map, separated by empty free space so that each one
+/// still starts with a file.
+pub fn day09(input: &str) -> String {
+    let mut disk = input.trim().to_string();
+    if disk.len() % 2 == 1 {
+        disk.push('0');
+    }
+    disk.repeat(4)
+}
+
+pub fn day10(input: &str) -> String {
+    tile(input, 3, &[])
+}
+
+pub fn day11(input: &str) -> String {
+    let mut rng = Rng::new(11);
+    let mut out = input.trim().to_string();
+    for _ in 0..200 {
+        let _ = write!(out, " {}", rng.below(1_000_000));
+    }
+    out
+}
+
+pub fn day12(input: &str) -> String {
+    tile(input, 3, &[])
+}
+
+pub fn day13(input: &str) -> String {
+    let mut out = String::new();
+    for _ in 0..20 {
+        out.push_str(input.trim());
+        out.push_str("\n\n");
+    }
+    out
+}
+
+/// Real robots, which still draw the tree, among random ones in the same area.
+pub fn day14(input: &str) -> String {
+    let mut rng = Rng::new(14);
+    let mut out = input.trim().to_string();
+    for _ in 0..2000 {
+        let (x, y) = (rng.below(101), rng.below(103));
+        let (vx, vy) = (rng.below(201) as i32 - 100, rng.below(207) as i32 - 103);
+        let _ = write!(out, "\np={x},{y} v={vx},{vy}");
+    }
+    out
+}
+
+pub fn day15(input: &str) -> String {
+    let (warehouse, moves) = input.trim().split_once("\n\n").unwrap();
+    format!("{}\n{}", tile(warehouse, 2, &['@']), repeat(moves, 4))
+}
+
+pub fn day16(_: &str) -> String {
+    maze(281, 16)
+}
+
+/// Register A large enough to output a few more values.
+pub fn day17(input: &str) -> String {
+    let (a, rest) = input.split_once('\n').unwrap();
+    let a: u64 = a.trim_start_matches("Register A: ").parse().unwrap();
+    format!("Register A: {}\n{rest}", a << 15)
+}
+
+/// Every byte of a larger memory space but the corners falls, in random order.
+pub fn day18(_: &str) -> String {
+    const SIZE: usize = 101;
+    let mut rng = Rng::new(18);
+    let mut bytes = (0..SIZE * SIZE)
+        .map(|i| (i % SIZE, i / SIZE))
+        .filter(|&(x, y)| (x, y) != (0, 0) && (x, y) != (SIZE - 1, SIZE - 1))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut bytes);
+    bytes.into_iter().fold(String::new(), |mut out, (x, y)| {
+        let _ = writeln!(out, "{x},{y}");
+        out
+    })
+}
+
+/// Same towels, with designs made of two real ones.
+pub fn day19(input: &str) -> String {
+    let (patterns, designs) = input.trim().split_once("\n\n").unwrap();
+    let designs = designs.lines().collect::<Vec<_>>();
+    let mut out = format!("{patterns}\n\n");
+    for (i, design) in designs.iter().enumerate() {
+        for other in designs.iter().skip(i + 1).take(2) {
+            out.push_str(design);
+            out.push_str(other);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+pub fn day20(_: &str) -> String {
+    maze(201, 20)
+}
@@ -0,0 +1,91 @@
+use std::time::{Duration, Instant};
+
+use aoc_core::{Params, ParseError, Solution};
+
+/// Solve the given parts of one day on `input` and print the answers until a
+/// part fails, then the report of [`Solution::explain`] when `explain` is set.
+pub type Runner =
+    fn(input: &str, parts: &[u8], params: &Params, explain: bool) -> Result<Timings, ParseError>;
+
+/// Time spent parsing the input and solving each part that was asked for.
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: [Option<Duration>; 2],
+}
+
+pub struct Day {
+    pub run: Runner,
+    /// Names of the parameters understood by the day.
+    pub params: &'static [&'static str],
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        run: run::<S>,
+        params: S::PARAMS,
+    }
+}
+
+/// Every day, `DAYS[0]` being day 1.
+pub const DAYS: [Day; 20] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day14::Day14>(),
+    day::<day15::Day15>(),
+    day::<day16::Day16>(),
+    day::<day17::Day17>(),
+    day::<day18::Day18>(),
+    day::<day19::Day19>(),
+    day::<day20::Day20>(),
+];
+
+fn run<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    params: &Params,
+    explain: bool,
+) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, params)?;
+    let mut timings = Timings {
+        parse: start.elapsed(),
+        parts: [None; 2],
+    };
+    // the report is printed even when a part fails, such as for an input
+    // that only part 2 cannot handle
+    let mut failed = None;
+    for &part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&parsed).map(|answer| answer.to_string()),
+            _ => S::part2(&parsed).map(|answer| answer.to_string()),
+        };
+        let elapsed = start.elapsed();
+        match answer {
+            Ok(answer) => println!("Day {:02} part {part}: {answer} ({elapsed:.2?})", S::DAY),
+            Err(e) => {
+                failed = Some(e);
+                break;
+            }
+        }
+        timings.parts[part as usize - 1] = Some(elapsed);
+    }
+    if let Some(report) = explain.then(|| S::explain(&parsed)).flatten() {
+        println!("Day {:02} explained:\n{}", S::DAY, report.trim_end());
+    }
+    match failed {
+        Some(e) => Err(e),
+        None => Ok(timings),
+    }
+}
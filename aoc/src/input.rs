@@ -0,0 +1,43 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory searched for `dayXX.txt` when no input is given.
+pub const DEFAULT_DIR: &str = "inputs";
+
+/// Where to read the puzzle input of a day from.
+pub enum Source {
+    /// `dayXX.txt` in the given directory.
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    pub fn new(arg: Option<&str>, dir: Option<&str>) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => Source::Dir(PathBuf::from(dir.unwrap_or(DEFAULT_DIR))),
+        }
+    }
+    pub fn read(&self, day: usize) -> Result<String, String> {
+        match self {
+            Source::Dir(dir) => read_file(&dir.join(format!("day{day:02}.txt"))),
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("cannot read input from stdin: {e}"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("cannot read input {}: {e}", path.display()))
+}
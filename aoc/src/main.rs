@@ -0,0 +1,195 @@
+use std::{
+    env,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Params, ParseError};
+
+mod days;
+mod input;
+
+use days::{Timings, DAYS};
+use input::Source;
+
+const USAGE: &str = "\
+usage: aoc run <DAY|all> [INPUT] [--part <1|2>] [--inputs <DIR>]
+               [--param <KEY=VALUE,...>] [--timings] [--explain]
+
+Solve one day of the calendar (or all of them) and print the answers with the
+time spent on each part, `--timings` ends with a table of the time spent
+parsing and solving each part of every day. `--explain` prints what some days
+can tell about their input on top of the answers:
+  day 05  cycles, transitive closure and reduction of the rules, unused rules
+  day 07  equations true with the operators of part 2, written out
+  day 14  area at the second of the tree
+  day 16  best paths drawn over the maze and their actions
+  day 17  listing of the program and trace of its run
+
+INPUT is the path of the puzzle input or `-` to read it from stdin, by default
+the input of day X is read from `<DIR>/dayXX.txt` (`inputs/dayXX.txt`).
+
+Some days take parameters that the input does not tell, they are inferred
+when not given:
+  day 07  ops             operators added to both parts, among `-` and `^`
+                          (xor) such as `-^`
+          wide            128-bit numbers (`true`), so that values beyond 64
+                          bits can come back down with `-`, on when the input
+                          does not fit in 64 bits
+  day 14  width, height   size of the area
+  day 16  forward, turn   cost of a step (times the digit of `1`-`9` tiles)
+                          and of a quarter turn, 1 and 1000 by default
+          uturn           whether the reindeer can turn around (`true`)
+  day 18  size            side of the memory space
+          bytes           number of fallen bytes in part 1
+          solver          `bisect` (binary search) or `union-find` in part 2
+          falling         bytes falling while walking (`true`): fewest steps
+                          and latest tick to leave at";
+
+/// Command line of the `run` command.
+struct Args {
+    /// Days to solve, 1-indexed.
+    days: Vec<usize>,
+    parts: Vec<u8>,
+    source: Source,
+    params: Params,
+    timings: bool,
+    explain: bool,
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("error: {msg}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("error: {msg}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let start = Instant::now();
+    let mut timings = Vec::new();
+    for &day in args.days.iter() {
+        let input = args.source.read(day)?;
+        let timing = (DAYS[day - 1].run)(&input, &args.parts, &args.params, args.explain)
+            .map_err(|e| diagnostic(day, &input, &e))?;
+        timings.push((day, timing));
+    }
+    if args.days.len() > 1 {
+        println!("Total: {:.2?}", start.elapsed());
+    }
+    if args.timings {
+        print_timings(&timings);
+    }
+    Ok(())
+}
+
+fn print_timings(timings: &[(usize, Timings)]) {
+    let cell = |d: Option<Duration>| d.map_or("-".to_string(), |d| format!("{d:.2?}"));
+    println!(
+        "\n{:<4}{:>12}{:>12}{:>12}{:>12}",
+        "day", "parse", "part 1", "part 2", "total"
+    );
+    let mut sum = Timings {
+        parse: Duration::ZERO,
+        parts: [None; 2],
+    };
+    for (day, t) in timings {
+        let total = t.parse + t.parts.iter().flatten().sum::<Duration>();
+        let [p1, p2] = t.parts.map(cell);
+        println!(
+            "{:<4}{:>12}{p1:>12}{p2:>12}{:>12}",
+            format!("{day:02}"),
+            cell(Some(t.parse)),
+            cell(Some(total))
+        );
+        sum.parse += t.parse;
+        for (s, p) in sum.parts.iter_mut().zip(t.parts) {
+            *s = p.map(|p| s.unwrap_or_default() + p);
+        }
+    }
+    if timings.len() > 1 {
+        let total = sum.parse + sum.parts.iter().flatten().sum::<Duration>();
+        let [p1, p2] = sum.parts.map(cell);
+        println!(
+            "{:<4}{:>12}{p1:>12}{p2:>12}{:>12}",
+            "all",
+            cell(Some(sum.parse)),
+            cell(Some(total))
+        );
+    }
+}
+
+/// Error message pointing at the offending character of the input.
+fn diagnostic(day: usize, input: &str, e: &ParseError) -> String {
+    if e.line == 0 {
+        return format!("day {day:02}: {e}");
+    }
+    let line = input.lines().nth(e.line - 1).unwrap_or_default();
+    let caret = " ".repeat(e.column.saturating_sub(1));
+    format!("day {day:02} input, {e}\n  | {line}\n  | {caret}^")
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter().map(String::as_str);
+    match args.next() {
+        Some("run") => {}
+        Some(cmd) => return Err(format!("unknown command `{cmd}`")),
+        None => return Err("missing command".to_string()),
+    }
+    let days = match args.next() {
+        Some("all") => (1..=DAYS.len()).collect(),
+        Some(day) => match day.parse() {
+            Ok(day) if (1..=DAYS.len()).contains(&day) => vec![day],
+            _ => return Err(format!("no solution for day `{day}`")),
+        },
+        None => return Err("missing day".to_string()),
+    };
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut dir = None;
+    let mut params = Vec::new();
+    let mut timings = false;
+    let mut explain = false;
+    while let Some(arg) = args.next() {
+        match arg {
+            "--part" | "-p" => match args.next() {
+                Some("1") => parts = vec![1],
+                Some("2") => parts = vec![2],
+                part => return Err(format!("invalid part `{}`", part.unwrap_or_default())),
+            },
+            "--inputs" => dir = Some(args.next().ok_or("missing inputs directory")?),
+            "--param" => params.push(args.next().ok_or("missing parameters")?),
+            "--timings" => timings = true,
+            "--explain" => explain = true,
+            arg if input.is_none() && (arg == "-" || !arg.starts_with('-')) => input = Some(arg),
+            arg => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    if input.is_some() && days.len() > 1 {
+        return Err("a single input cannot be used for all days".to_string());
+    }
+    let params: Params = params.join(",").parse()?;
+    for key in params.keys() {
+        if !days.iter().any(|&day| DAYS[day - 1].params.contains(&key)) {
+            return Err(format!("unknown parameter `{key}`"));
+        }
+    }
+    Ok(Args {
+        days,
+        parts,
+        source: Source::new(input, dir),
+        params,
+        timings,
+        explain,
+    })
+}
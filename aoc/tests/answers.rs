@@ -0,0 +1,116 @@
+//! Every day solved on its puzzle input in `inputs/`, against the answers
+//! listed in `inputs/answers.txt`.
+
+use std::{fs, path::PathBuf};
+
+use aoc_core::{Params, Solution};
+
+fn inputs_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../inputs")
+}
+
+/// Answers of `day` as `[part1, part2]`.
+fn expected(day: u8) -> [String; 2] {
+    let path = inputs_dir().join("answers.txt");
+    let answers =
+        fs::read_to_string(&path).unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()));
+    answers
+        .lines()
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .find_map(|l| match l.split_whitespace().collect::<Vec<_>>()[..] {
+            [d, part1, part2] if d.parse() == Ok(day) => Some([part1, part2].map(String::from)),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("no answers of day {day} in {}", path.display()))
+}
+
+fn check<S: Solution>() {
+    let path = inputs_dir().join(format!("day{:02}.txt", S::DAY));
+    let input =
+        fs::read_to_string(&path).unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()));
+    let parsed = S::parse_with(&input, &Params::default()).unwrap();
+    let [part1, part2] = expected(S::DAY);
+    assert_eq!(S::part1(&parsed).unwrap().to_string(), part1, "part 1");
+    assert_eq!(S::part2(&parsed).unwrap().to_string(), part2, "part 2");
+}
+
+#[test]
+fn day01() {
+    check::<day01::Day01>();
+}
+#[test]
+fn day02() {
+    check::<day02::Day02>();
+}
+#[test]
+fn day03() {
+    check::<day03::Day03>();
+}
+#[test]
+fn day04() {
+    check::<day04::Day04>();
+}
+#[test]
+fn day05() {
+    check::<day05::Day05>();
+}
+#[test]
+fn day06() {
+    check::<day06::Day06>();
+}
+#[test]
+fn day07() {
+    check::<day07::Day07>();
+}
+#[test]
+fn day08() {
+    check::<day08::Day08>();
+}
+#[test]
+fn day09() {
+    check::<day09::Day09>();
+}
+#[test]
+fn day10() {
+    check::<day10::Day10>();
+}
+#[test]
+fn day11() {
+    check::<day11::Day11>();
+}
+#[test]
+fn day12() {
+    check::<day12::Day12>();
+}
+#[test]
+fn day13() {
+    check::<day13::Day13>();
+}
+#[test]
+fn day14() {
+    check::<day14::Day14>();
+}
+#[test]
+fn day15() {
+    check::<day15::Day15>();
+}
+#[test]
+fn day16() {
+    check::<day16::Day16>();
+}
+#[test]
+fn day17() {
+    check::<day17::Day17>();
+}
+#[test]
+fn day18() {
+    check::<day18::Day18>();
+}
+#[test]
+fn day19() {
+    check::<day19::Day19>();
+}
+#[test]
+fn day20() {
+    check::<day20::Day20>();
+}
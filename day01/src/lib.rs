@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use aoc_core::{parse_token, split_token, ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input<'a> = [Vec<u32>; 2];
+    type Answer1 = u32;
+    type Answer2 = u32;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut list1 = Vec::new();
+        let mut list2 = Vec::new();
+        for line in input.trim().lines() {
+            let (n1, n2) = split_token(input, line, "   ")?;
+            list1.push(parse_token(input, n1)?);
+            list2.push(parse_token(input, n2)?);
+        }
+        Ok([list1, list2])
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1([list1, list2]: &[Vec<u32>; 2]) -> u32 {
+    let mut list1 = list1.clone();
+    let mut list2 = list2.clone();
+    list1.sort();
+    list2.sort();
+    list1
+        .into_iter()
+        .zip(list2)
+        .map(|(n1, n2)| n2.abs_diff(n1))
+        .sum()
+}
+
+fn part2([list1, list2]: &[Vec<u32>; 2]) -> u32 {
+    let mut occurences: HashMap<u32, u32> = HashMap::new();
+    for &n2 in list2 {
+        *occurences.entry(n2).or_insert(0) += 1;
+    }
+    list1
+        .iter()
+        .map(|n1| n1 * *occurences.get(n1).unwrap_or(&0))
+        .sum()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&Day01::parse(EXAMPLE).unwrap()), 11);
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Day01::parse(EXAMPLE).unwrap()), 31);
+}
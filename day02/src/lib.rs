@@ -0,0 +1,98 @@
+use aoc_core::{parse_token, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(reports: &[Vec<i32>]) -> usize {
+    reports
+        .iter()
+        .filter(|numbers| get_unsafe_idx(numbers).is_none())
+        .count()
+}
+
+fn part2(reports: &[Vec<i32>]) -> usize {
+    reports
+        .iter()
+        .filter_map(|numbers| {
+            let mut numbers = numbers.clone();
+            get_unsafe_idx(&numbers)
+                .is_none_or(|i| {
+                    let num = numbers.remove(i);
+                    get_unsafe_idx(&numbers).is_none_or(|_| {
+                        numbers[i] = num;
+                        get_unsafe_idx(&numbers).is_none()
+                    })
+                })
+                .then_some(1)
+        })
+        .count()
+}
+
+/// Index of the first level of a pair that breaks the report, none for a
+/// safe report (one with a single level included).
+fn get_unsafe_idx(numbers: &[i32]) -> Option<usize> {
+    let [first, second, ..] = *numbers else {
+        return None;
+    };
+    let sign = (second - first).signum();
+    if sign == 0 {
+        return Some(0);
+    }
+    numbers
+        .windows(2)
+        .position(|t| !((1..=3).contains(&((t[1] - t[0]) * sign))))
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let numbers = line
+                .split_ascii_whitespace()
+                .map(|n| parse_token(input, n))
+                .collect::<Result<Vec<i32>, ParseError>>()?;
+            if numbers.len() < 2 {
+                return Err(ParseError::at(input, line, "expected at least 2 levels"));
+            }
+            Ok(numbers)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&parse(EXAMPLE).unwrap()), 2);
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&parse(EXAMPLE).unwrap()), 4);
+    // a single level left is safe
+    assert_eq!(part2(&parse("1 1\n5 5\n1 9").unwrap()), 3);
+    assert_eq!(part1(&parse("1 1\n5 5\n1 3").unwrap()), 1);
+}
@@ -0,0 +1,99 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse(input))
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+/// Valid instructions found in the corrupted memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// Product of a `mul(X,Y)`.
+    Mul(u32),
+    Do,
+    Dont,
+}
+
+fn part1(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .map(|instr| match instr {
+            Instruction::Mul(mul) => *mul,
+            _ => 0,
+        })
+        .sum()
+}
+fn part2(instructions: &[Instruction]) -> u32 {
+    let mut do_mul = true;
+    let mut res = 0;
+    for instr in instructions {
+        match instr {
+            Instruction::Do => do_mul = true,
+            Instruction::Dont => do_mul = false,
+            Instruction::Mul(mul) if do_mul => res += mul,
+            Instruction::Mul(_) => {}
+        }
+    }
+    res
+}
+
+fn parse(input: &str) -> Vec<Instruction> {
+    let bytes = input.as_bytes();
+    let mut instructions = Vec::new();
+    for i in 0..bytes.len().saturating_sub(4) {
+        if &bytes[i..(i + 4)] == b"do()" {
+            instructions.push(Instruction::Do);
+        } else if i + 7 < bytes.len() && &bytes[i..(i + 7)] == b"don't()" {
+            instructions.push(Instruction::Dont);
+        } else if &bytes[i..(i + 4)] == b"mul(" {
+            let substring = input.split_at(i + 4).1;
+            if let Some(mul) = parse_mul(substring) {
+                instructions.push(Instruction::Mul(mul));
+            }
+        }
+    }
+    instructions
+}
+fn parse_mul(s: &str) -> Option<u32> {
+    let (n1, rest) = s.split_once(",")?;
+    let (n2, _) = rest.split_once(")")?;
+    Some(parse_number(n1)? * parse_number(n2)?)
+}
+/// A number of 1 to 3 digits, anything else is not an instruction.
+fn parse_number(s: &str) -> Option<u32> {
+    let digits = (1..=3).contains(&s.len()) && s.bytes().all(|c| c.is_ascii_digit());
+    digits.then(|| s.parse().ok()).flatten()
+}
+
+#[test]
+fn test1() {
+    const EXAMPLE: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    assert_eq!(part1(&parse(EXAMPLE)), 161);
+}
+#[test]
+fn test2() {
+    const EXAMPLE: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    assert_eq!(part2(&parse(EXAMPLE)), 48);
+}
+#[test]
+fn test_long_numbers() {
+    assert_eq!(
+        part1(&parse("mul(1000,2)mul(2,1000)mul(+2,3)mul(999,2)")),
+        1998
+    );
+    assert_eq!(part1(&parse("mul(99999999999,99999999999)")), 0);
+}
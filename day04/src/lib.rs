@@ -0,0 +1,81 @@
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Coord, Grid};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(input, Some)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(grid: &Grid<u8>) -> usize {
+    grid.coords().map(|c| n_xmas1(grid, c)).sum()
+}
+fn part2(grid: &Grid<u8>) -> usize {
+    grid.coords().map(|c| n_xmas2(grid, c)).sum()
+}
+
+fn n_xmas1(grid: &Grid<u8>, coord: Coord) -> usize {
+    if grid[coord] != b'X' {
+        return 0;
+    }
+    Coord::DIRECTIONS8
+        .into_iter()
+        .filter(|&dir| xmas1_dir(grid, coord, dir).is_some())
+        .count()
+}
+fn n_xmas2(grid: &Grid<u8>, coord: Coord) -> usize {
+    if grid[coord] != b'A' {
+        return 0;
+    }
+    let mut total: usize = 0;
+    for dir in Coord::DIAGONALS {
+        if grid.get(coord + dir) == Some(&b'M') && grid.get(coord - dir) == Some(&b'S') {
+            total += 1;
+        }
+    }
+    total.saturating_sub(1)
+}
+fn xmas1_dir(grid: &Grid<u8>, mut coord: Coord, dir: Coord) -> Option<()> {
+    for chr in [b'M', b'A', b'S'] {
+        coord += dir;
+        if &chr != grid.get(coord)? {
+            return None;
+        }
+    }
+    Some(())
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&Day04::parse(EXAMPLE).unwrap()), 18);
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Day04::parse(EXAMPLE).unwrap()), 9);
+}
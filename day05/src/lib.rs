@@ -0,0 +1,388 @@
+use std::collections::HashSet;
+
+use aoc_core::{parse_token, split_token, ParseError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input<'a> = (Rules, Updates);
+    type Answer1 = u32;
+    type Answer2 = u32;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+    fn part1((rules, updates): &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(rules, updates))
+    }
+    fn part2((rules, updates): &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        part2(rules, updates)
+    }
+    fn explain((rules, updates): &Self::Input<'_>) -> Option<String> {
+        Some(explain(rules, updates))
+    }
+}
+
+fn part1(rules: &Rules, updates: &[Vec<u8>]) -> u32 {
+    updates
+        .iter()
+        .filter(|v| rules.is_ordered(v))
+        .map(|v| v[v.len() / 2] as u32)
+        .sum()
+}
+fn part2(rules: &Rules, updates: &[Vec<u8>]) -> Result<u32, ParseError> {
+    let mut res = 0;
+    for v in updates.iter().filter(|v| !rules.is_ordered(v)) {
+        let sorted = rules.sort(v).map_err(|cycle| {
+            let update = v.iter().map(u8::to_string).collect::<Vec<_>>().join(",");
+            let cycle = cycle
+                .windows(2)
+                .map(|w| format!("{}|{}", w[0], w[1]))
+                .collect::<Vec<_>>()
+                .join(", ");
+            ParseError::unlocated(format!("cycle in the rules of update `{update}`: {cycle}"))
+        })?;
+        res += sorted[sorted.len() / 2] as u32;
+    }
+    Ok(res)
+}
+
+/// Analysis of the whole rule graph, beyond the pages of each update.
+fn explain(rules: &Rules, updates: &[Vec<u8>]) -> String {
+    let closure = rules.closure();
+    let reduction = rules.reduction();
+    let unused = rules.unused(updates);
+    let cyclic = closure
+        .components()
+        .into_iter()
+        .filter(|c| closure.precedes(c[0], c[0]))
+        .collect::<Vec<_>>();
+    let mut out = format!(
+        "{} rules between {} pages\n",
+        rules.iter().count(),
+        rules.pages().len()
+    );
+    out += &format!("cyclic components: {}\n", cyclic.len());
+    for component in &cyclic {
+        let cycle = rules.cycle(component);
+        out += &format!(
+            "  {} pages, such as {}\n",
+            component.len(),
+            list(cycle.windows(2).map(|w| (w[0], w[1])))
+        );
+    }
+    out += &format!(
+        "transitive closure: {} rules, {} implied ones\n",
+        closure.iter().count(),
+        closure
+            .iter()
+            .filter(|&(a, b)| !rules.precedes(a, b))
+            .count()
+    );
+    out += &format!(
+        "transitive reduction: {} rules\n  {}\n",
+        reduction.iter().count(),
+        list(reduction.iter())
+    );
+    out += &format!("unused rules: {}\n", unused.len());
+    if !unused.is_empty() {
+        out += &format!("  {}\n", list(unused.into_iter()));
+    }
+    out
+}
+fn list(rules: impl Iterator<Item = (u8, u8)>) -> String {
+    rules
+        .map(|(a, b)| format!("{a}|{b}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Set of pages, one bit per page number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct PageSet([u64; 4]);
+
+impl PageSet {
+    fn insert(&mut self, page: u8) {
+        self.0[page as usize / 64] |= 1 << (page % 64);
+    }
+    fn contains(&self, page: u8) -> bool {
+        self.0[page as usize / 64] & (1 << (page % 64)) != 0
+    }
+    fn union(&mut self, other: &PageSet) {
+        for (word, other) in self.0.iter_mut().zip(other.0) {
+            *word |= other;
+        }
+    }
+    fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+    fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=255).filter(|&page| self.contains(page))
+    }
+}
+
+/// Page ordering rules, as the set of pages that must come after each page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    after: Vec<PageSet>,
+}
+
+impl Rules {
+    fn new(rules: impl IntoIterator<Item = (u8, u8)>) -> Self {
+        let mut after = vec![PageSet::default(); 256];
+        for (before, page) in rules {
+            after[before as usize].insert(page);
+        }
+        Rules { after }
+    }
+    /// Every rule, sorted.
+    fn iter(&self) -> impl Iterator<Item = (u8, u8)> + '_ {
+        (0..=255).flat_map(move |a| self.after[a as usize].iter().map(move |b| (a, b)))
+    }
+    /// Pages appearing in at least one rule.
+    fn pages(&self) -> PageSet {
+        let mut pages = PageSet::default();
+        for (a, b) in self.iter() {
+            pages.insert(a);
+            pages.insert(b);
+        }
+        pages
+    }
+    /// Whether a rule asks for `a` to be printed before `b`.
+    fn precedes(&self, a: u8, b: u8) -> bool {
+        self.after[a as usize].contains(b)
+    }
+    fn is_ordered(&self, pages: &[u8]) -> bool {
+        pages
+            .iter()
+            .enumerate()
+            .all(|(i, &a)| pages[i + 1..].iter().all(|&b| !self.precedes(b, a)))
+    }
+    /// Topological sort of `pages` along the rules between them, or the pages
+    /// of a cycle of rules (the first one repeated at the end) when there is no
+    /// order satisfying all of them.
+    fn sort(&self, pages: &[u8]) -> Result<Vec<u8>, Vec<u8>> {
+        let mut remaining = pages.to_vec();
+        let mut sorted = Vec::with_capacity(pages.len());
+        while !remaining.is_empty() {
+            let first = remaining
+                .iter()
+                .position(|&b| remaining.iter().all(|&a| !self.precedes(a, b)));
+            let Some(first) = first else {
+                return Err(self.cycle(&remaining));
+            };
+            sorted.push(remaining.remove(first));
+        }
+        Ok(sorted)
+    }
+    /// Cycle among `pages`, which all have a predecessor among them.
+    fn cycle(&self, pages: &[u8]) -> Vec<u8> {
+        let mut path = vec![pages[0]];
+        loop {
+            let cur = path[path.len() - 1];
+            let prev = pages.iter().copied().find(|&a| self.precedes(a, cur));
+            let prev = prev.unwrap_or(cur);
+            if let Some(start) = path.iter().position(|&p| p == prev) {
+                let mut cycle = path[start..].to_vec();
+                cycle.reverse();
+                cycle.push(cycle[0]);
+                return cycle;
+            }
+            path.push(prev);
+        }
+    }
+    /// Rules implied by chaining the rules, pages of a cycle being ordered
+    /// before themselves.
+    fn closure(&self) -> Rules {
+        let mut after = self.after.clone();
+        for k in 0..=255 {
+            for i in 0..after.len() {
+                if after[i].contains(k) {
+                    let after_k = after[k as usize];
+                    after[i].union(&after_k);
+                }
+            }
+        }
+        Rules { after }
+    }
+    /// Strongly connected components of the pages, sorted, for rules that
+    /// are their own [`Rules::closure`].
+    fn components(&self) -> Vec<Vec<u8>> {
+        let mut components: Vec<Vec<u8>> = Vec::new();
+        for page in self.pages().iter() {
+            let same = |c: &&mut Vec<u8>| self.precedes(page, c[0]) && self.precedes(c[0], page);
+            match components.iter_mut().find(|c| same(c)) {
+                Some(component) => component.push(page),
+                None => components.push(vec![page]),
+            }
+        }
+        components
+    }
+    /// Fewest rules with the same closure: a cycle through each component of
+    /// pages ordered in a cycle, and the rules between components that no
+    /// other component implies.
+    fn reduction(&self) -> Rules {
+        let closure = self.closure();
+        let components = closure.components();
+        let mut component_of = [usize::MAX; 256];
+        for (i, component) in components.iter().enumerate() {
+            for &page in component {
+                component_of[page as usize] = i;
+            }
+        }
+        let mut rules = Vec::new();
+        for component in &components {
+            if closure.precedes(component[0], component[0]) {
+                let next = component.iter().cycle().skip(1);
+                rules.extend(component.iter().copied().zip(next.copied()));
+            }
+        }
+        let mut linked = HashSet::new();
+        for (a, b) in self.iter() {
+            let (ca, cb) = (component_of[a as usize], component_of[b as usize]);
+            let implied = components.iter().enumerate().any(|(i, c)| {
+                i != ca && i != cb && closure.precedes(a, c[0]) && closure.precedes(c[0], b)
+            });
+            if ca != cb && !implied && linked.insert((ca, cb)) {
+                rules.push((a, b));
+            }
+        }
+        Rules::new(rules)
+    }
+    /// Rules between pages never printed in the same update.
+    fn unused(&self, updates: &[Vec<u8>]) -> Vec<(u8, u8)> {
+        let updates = updates
+            .iter()
+            .map(|pages| {
+                let mut set = PageSet::default();
+                pages.iter().for_each(|&page| set.insert(page));
+                set
+            })
+            .collect::<Vec<_>>();
+        self.iter()
+            .filter(|&(a, b)| !updates.iter().any(|set| set.contains(a) && set.contains(b)))
+            .collect()
+    }
+}
+
+/// Pages of each update.
+pub type Updates = Vec<Vec<u8>>;
+
+fn parse(input: &str) -> Result<(Rules, Updates), ParseError> {
+    // the updates may be missing, but not the blank line before them
+    let (rules, updates) = split_token(input, input.trim_start(), "\n\n")?;
+    let rules = rules
+        .lines()
+        .map(|l| {
+            let (n1, n2) = split_token(input, l, "|")?;
+            Ok((parse_token(input, n1)?, parse_token(input, n2)?))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let updates = updates
+        .trim()
+        .lines()
+        .map(|l| {
+            l.split(',')
+                .map(|x| parse_token(input, x))
+                .collect::<Result<Vec<u8>, ParseError>>()
+        })
+        .collect::<Result<_, ParseError>>()?;
+    Ok((Rules::new(rules), updates))
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+#[test]
+fn test1() {
+    let (rules, updates) = parse(EXAMPLE).unwrap();
+    assert_eq!(part1(&rules, &updates), 143);
+}
+#[test]
+fn test2() {
+    let (rules, updates) = parse(EXAMPLE).unwrap();
+    assert_eq!(part2(&rules, &updates), Ok(123));
+}
+#[test]
+fn test_cycle() {
+    let (rules, updates) = parse("47|53\n53|29\n29|47\n75|47\n\n75,29,53,47").unwrap();
+    assert_eq!(
+        part2(&rules, &updates),
+        Err(ParseError::unlocated(
+            "cycle in the rules of update `75,29,53,47`: 47|53, 53|29, 29|47"
+        ))
+    );
+}
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        parse("47|53\n97|13\n\n75,47,6x"),
+        Err(ParseError::new(
+            4,
+            7,
+            "cannot parse `6x`: invalid digit found in string"
+        ))
+    );
+    assert_eq!(
+        parse("47|53\n97-13\n"),
+        Err(ParseError::new(
+            1,
+            1,
+            "expected `\\n\\n` in `47|53\\n97-13\\n`"
+        ))
+    );
+    let (rules, updates) = parse("1|2\n\n").unwrap();
+    assert_eq!(rules.iter().count(), 1);
+    assert!(updates.is_empty());
+}
+#[test]
+fn test_explain() {
+    let (rules, updates) = parse("1|2\n2|3\n1|3\n3|4\n4|3\n5|6\n\n1,2,3\n3,4").unwrap();
+    assert_eq!(rules.closure().iter().count(), 3 + 2 + 2 + 2 + 1);
+    assert_eq!(
+        rules.reduction().iter().collect::<Vec<_>>(),
+        [(1, 2), (2, 3), (3, 4), (4, 3), (5, 6)]
+    );
+    assert_eq!(rules.unused(&updates), [(5, 6)]);
+    assert_eq!(
+        explain(&rules, &updates),
+        "6 rules between 6 pages
+cyclic components: 1
+  2 pages, such as 4|3, 3|4
+transitive closure: 10 rules, 4 implied ones
+transitive reduction: 5 rules
+  1|2, 2|3, 3|4, 4|3, 5|6
+unused rules: 1
+  5|6
+"
+    );
+}
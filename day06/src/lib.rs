@@ -0,0 +1,197 @@
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Coord, Grid};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input<'a> = Lab;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Lab::new(input)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        part2(input)
+    }
+}
+
+fn walks_in_loop() -> ParseError {
+    ParseError::unlocated("guard walks in a loop without leaving the lab")
+}
+
+fn part1(lab: &Lab) -> Result<usize, ParseError> {
+    let mut seen = BitSet::new(lab.grid.len());
+    let mut states = BitSet::new(lab.grid.len() * 4);
+    let mut state = Some((lab.init, UP));
+    while let Some((cur, dir)) = state {
+        if !states.insert(4 * cur + dir) {
+            return Err(walks_in_loop());
+        }
+        seen.insert(cur);
+        state = lab.step(cur, dir);
+    }
+    Ok(seen.len())
+}
+fn part2(lab: &Lab) -> Result<usize, ParseError> {
+    // an obstruction is tried the first time the guard is about to step on its
+    // cell, the guard can then start walking from there instead of the start
+    let mut tried = BitSet::new(lab.grid.len());
+    let mut visited = BitSet::new(lab.grid.len() * 4);
+    let mut states = BitSet::new(lab.grid.len() * 4);
+    tried.insert(lab.init);
+    let (mut cur, mut dir) = (lab.init, UP);
+    let mut count = 0;
+    while let Some((next, next_dir)) = lab.step(cur, dir) {
+        if !states.insert(4 * cur + dir) {
+            return Err(walks_in_loop());
+        }
+        if next != cur && tried.insert(next) && lab.loops(cur, dir, next, &mut visited) {
+            count += 1;
+        }
+        (cur, dir) = (next, next_dir);
+    }
+    Ok(count)
+}
+
+/// Index of [`Coord::UP`] in [`Coord::DIRECTIONS`], where the guard faces first.
+const UP: usize = 3;
+/// Jump of a guard leaving the lab.
+const EXIT: u32 = u32::MAX;
+
+#[derive(Clone)]
+pub struct Lab {
+    grid: Grid<u8>,
+    /// Index of the cell where the guard starts.
+    init: usize,
+    /// For every cell and direction (`4 * idx + dir`, in the order of
+    /// [`Coord::DIRECTIONS`]), the cell where the guard stops in front of the
+    /// next obstruction, or [`EXIT`].
+    jumps: Vec<u32>,
+}
+
+impl Lab {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| b".#^".contains(&c).then_some(c))?;
+        let init = grid
+            .iter()
+            .position(|&x| x == b'^')
+            .ok_or_else(|| ParseError::eof(input, "missing guard `^`"))?;
+        let mut jumps = vec![EXIT; grid.len() * 4];
+        for (dir, delta) in Coord::<isize>::DIRECTIONS.into_iter().enumerate() {
+            let mut fill = |idx: usize| {
+                jumps[4 * idx + dir] = match grid.idx(grid.pos::<isize>(idx) + delta) {
+                    None => EXIT,
+                    Some(next) if grid[next] == b'#' => idx as u32,
+                    Some(next) => jumps[4 * next + dir],
+                };
+            };
+            // the cell ahead must be filled first
+            if delta == Coord::LEFT || delta == Coord::UP {
+                (0..grid.len()).for_each(&mut fill);
+            } else {
+                (0..grid.len()).rev().for_each(&mut fill);
+            }
+        }
+        Ok(Lab { grid, init, jumps })
+    }
+    /// Next cell and direction of the guard, one step forward or a turn.
+    fn step(&self, cur: usize, dir: usize) -> Option<(usize, usize)> {
+        let next = self
+            .grid
+            .idx(self.grid.pos::<isize>(cur) + Coord::DIRECTIONS[dir])?;
+        Some(if self.grid[next] == b'#' {
+            (cur, (dir + 1) % 4)
+        } else {
+            (next, dir)
+        })
+    }
+    /// Whether the guard at `cur` facing `dir` walks in a loop once an
+    /// obstruction is added on the cell `wall`, jumping from wall to wall.
+    fn loops(&self, mut cur: usize, mut dir: usize, wall: usize, visited: &mut BitSet) -> bool {
+        visited.clear();
+        let wall_pos = self.grid.pos::<isize>(wall);
+        while visited.insert(4 * cur + dir) {
+            let pos = self.grid.pos::<isize>(cur);
+            let delta = Coord::DIRECTIONS[dir];
+            let jump = self.jumps[4 * cur + dir];
+            // the added obstruction stops the guard when it is ahead of it,
+            // before the next one
+            let dist = pos.manhattan(wall_pos);
+            let ahead = pos + delta * dist as isize == wall_pos;
+            if ahead && (jump == EXIT || dist <= pos.manhattan(self.grid.pos(jump as usize))) {
+                cur = self.grid.idx(wall_pos - delta).unwrap_or_default();
+            } else if jump == EXIT {
+                return false;
+            } else {
+                cur = jump as usize;
+            }
+            dir = (dir + 1) % 4;
+        }
+        true
+    }
+}
+
+/// Dense set of indices below a fixed size.
+struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    fn new(size: usize) -> Self {
+        BitSet {
+            words: vec![0; size.div_ceil(64)],
+            len: 0,
+        }
+    }
+    /// Whether `i` was not in the set yet.
+    fn insert(&mut self, i: usize) -> bool {
+        let (word, bit) = (&mut self.words[i / 64], 1 << (i % 64));
+        let new = *word & bit == 0;
+        *word |= bit;
+        self.len += new as usize;
+        new
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&Lab::new(EXAMPLE).unwrap()), Ok(41));
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Lab::new(EXAMPLE).unwrap()), Ok(6));
+}
+#[test]
+fn test_guard_loop() {
+    for lab in [".#.\n#^#\n.#.", ".#..\n...#\n#^..\n..#."] {
+        let lab = Lab::new(lab).unwrap();
+        assert_eq!(part1(&lab), Err(walks_in_loop()));
+        assert_eq!(part2(&lab), Err(walks_in_loop()));
+    }
+}
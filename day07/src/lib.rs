@@ -0,0 +1,576 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{BitXor, Div, Rem},
+    str::FromStr,
+};
+
+use aoc_core::{parse_token, split_token, Params, ParseError, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input<'a> = Calibration;
+    type Answer1 = u128;
+    type Answer2 = u128;
+    const PARAMS: &'static [&'static str] = &["ops", "wide"];
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let mut calibration = parse(input)?;
+        if let Some(Operators(extra)) = params.get("ops")? {
+            calibration.extra = extra;
+        }
+        if params.get("wide")?.unwrap_or(false) {
+            calibration.equations = calibration.equations.widen();
+        }
+        Ok(calibration)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        part2(input)
+    }
+    fn explain(input: &Self::Input<'_>) -> Option<String> {
+        Some(explain(input))
+    }
+}
+
+fn part1(calibration: &Calibration) -> Result<u128, ParseError> {
+    calibration.part_x(&["+", "*"])
+}
+fn part2(calibration: &Calibration) -> Result<u128, ParseError> {
+    calibration.part_x(&["+", "*", "||"])
+}
+
+/// Every equation true with the operators of part 2, written out.
+fn explain(calibration: &Calibration) -> String {
+    let symbols = calibration.symbols(&["+", "*", "||"]);
+    match &calibration.equations {
+        Equations::Narrow(equations) => explain_equations(equations, &symbols),
+        Equations::Wide(equations) => explain_equations(equations, &symbols),
+    }
+}
+fn explain_equations<N: Number>(equations: &[Equation<N>], symbols: &[&str]) -> String {
+    let ops = Operator::with_symbols(symbols);
+    let mut out = String::new();
+    let mut n_true = 0;
+    for (ans, terms) in equations {
+        if let Some(solution) = solve(*ans, terms, &ops) {
+            n_true += 1;
+            out += &expression(*ans, terms, &solution);
+            out.push('\n');
+        }
+    }
+    let header = format!(
+        "{n_true} of {} equations true with {}\n",
+        equations.len(),
+        symbols.join(" ")
+    );
+    header + &out
+}
+/// `ans = t0 op1 t1 op2 t2 ...`, evaluated from left to right.
+fn expression<N: Number>(ans: N, terms: &[N], solution: &[&Operator<N>]) -> String {
+    let mut out = format!("{ans} = {}", terms[0]);
+    for (op, term) in solution.iter().zip(&terms[1..]) {
+        out += &format!(" {} {term}", op.symbol);
+    }
+    out
+}
+
+/// Unsigned integer the equations are solved with.
+pub trait Number:
+    Copy
+    + Eq
+    + Ord
+    + Debug
+    + Display
+    + Into<u128>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + BitXor<Output = Self>
+{
+    const ZERO: Self;
+    const TEN: Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+                const TEN: Self = 10;
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+impl_number!(u64, u128);
+
+/// Calibration result and the terms (at least one) to combine into it.
+pub type Equation<N = u64> = (N, Vec<N>);
+
+/// Equations of the input, in 64 bits unless some number does not fit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Equations {
+    Narrow(Vec<Equation<u64>>),
+    Wide(Vec<Equation<u128>>),
+}
+
+impl Equations {
+    /// Same equations in 128 bits, so that values beyond 64 bits along the
+    /// way can still come back down to the answer with `-`.
+    fn widen(self) -> Self {
+        match self {
+            Equations::Narrow(equations) => Equations::Wide(
+                equations
+                    .into_iter()
+                    .map(|(ans, terms)| (ans.into(), terms.into_iter().map(Into::into).collect()))
+                    .collect(),
+            ),
+            wide => wide,
+        }
+    }
+}
+
+/// Equations of the input, with the operators added to both parts.
+#[derive(Debug, Clone)]
+pub struct Calibration {
+    pub equations: Equations,
+    /// Symbols of the added operators.
+    pub extra: Vec<&'static str>,
+}
+
+impl Calibration {
+    fn symbols<'a>(&'a self, base: &[&'a str]) -> Vec<&'a str> {
+        let mut symbols = base.to_vec();
+        symbols.extend(self.extra.iter().filter(|op| !base.contains(op)));
+        symbols
+    }
+    fn part_x(&self, base: &[&str]) -> Result<u128, ParseError> {
+        let symbols = self.symbols(base);
+        match &self.equations {
+            Equations::Narrow(equations) => part_x(equations, &Operator::with_symbols(&symbols)),
+            Equations::Wide(equations) => part_x(equations, &Operator::with_symbols(&symbols)),
+        }
+    }
+}
+
+/// Operator combining the value so far with the next term, `None` when the
+/// result is not a natural number or does not fit in `N`.
+///
+/// A value too large is beyond any answer: only `-` can bring it back down,
+/// which the [`Equations::Wide`] numbers leave more room for.
+#[derive(Debug, Clone, Copy)]
+pub struct Operator<N = u64> {
+    pub symbol: &'static str,
+    pub apply: fn(N, N) -> Option<N>,
+    /// Inverse of `apply` given the term, without which the equations are
+    /// solved by trying every operator from the first term.
+    pub undo: Option<fn(N, N) -> Undo<N>>,
+}
+
+impl<N> PartialEq for Operator<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.symbol == other.symbol
+    }
+}
+
+/// Values that an operator combines with a term into a given value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Undo<N = u64> {
+    Impossible,
+    Value(N),
+    /// Any value, such as for a multiplication by 0.
+    Any,
+}
+
+impl<N> Undo<N> {
+    fn from(value: Option<N>) -> Self {
+        value.map_or(Undo::Impossible, Undo::Value)
+    }
+}
+
+impl<N: Number> Operator<N> {
+    pub const ADD: Self = Operator {
+        symbol: "+",
+        apply: N::checked_add,
+        undo: Some(|value, term| Undo::from(value.checked_sub(term))),
+    };
+    pub const MUL: Self = Operator {
+        symbol: "*",
+        apply: N::checked_mul,
+        undo: Some(|value, term| {
+            if term == N::ZERO {
+                return if value == N::ZERO {
+                    Undo::Any
+                } else {
+                    Undo::Impossible
+                };
+            }
+            Undo::from((value % term == N::ZERO).then(|| value / term))
+        }),
+    };
+    /// Digits of the value followed by the digits of the term.
+    pub const CONCAT: Self = Operator {
+        symbol: "||",
+        apply: |acc, term| match power_above(term) {
+            Some(power) => acc.checked_mul(power)?.checked_add(term),
+            // only 0 followed by the term fits
+            None => (acc == N::ZERO).then_some(term),
+        },
+        undo: Some(|value, term| match power_above(term) {
+            Some(power) => Undo::from((value % power == term).then(|| value / power)),
+            None => Undo::from((value == term).then_some(N::ZERO)),
+        }),
+    };
+    pub const SUB: Self = Operator {
+        symbol: "-",
+        apply: N::checked_sub,
+        undo: Some(|value, term| Undo::from(value.checked_add(term))),
+    };
+    pub const XOR: Self = Operator {
+        symbol: "^",
+        apply: |acc, term| Some(acc ^ term),
+        undo: Some(|value, term| Undo::Value(value ^ term)),
+    };
+    /// Every operator, by symbol.
+    pub const ALL: [Self; 5] = [Self::ADD, Self::MUL, Self::CONCAT, Self::SUB, Self::XOR];
+
+    /// Operators of the given symbols, which must be among [`Operator::ALL`].
+    fn with_symbols(symbols: &[&str]) -> Vec<Self> {
+        symbols
+            .iter()
+            .filter_map(|&symbol| Self::ALL.into_iter().find(|op| op.symbol == symbol))
+            .collect()
+    }
+}
+
+/// Lowest power of 10 above `n`, if it fits in `N`.
+fn power_above<N: Number>(n: N) -> Option<N> {
+    let mut power = N::TEN;
+    while n >= power {
+        power = power.checked_mul(N::TEN)?;
+    }
+    Some(power)
+}
+
+/// Symbols of operators given one after the other, such as `-^`.
+struct Operators(Vec<&'static str>);
+
+impl FromStr for Operators {
+    type Err = String;
+    fn from_str(mut s: &str) -> Result<Self, Self::Err> {
+        let all = Operator::<u64>::ALL.map(|op| op.symbol);
+        let mut symbols = Vec::new();
+        while !s.is_empty() {
+            let symbol = all.into_iter().find(|symbol| s.starts_with(symbol));
+            let symbol = symbol.ok_or_else(|| {
+                let all = all.map(|symbol| format!("`{symbol}`"));
+                format!("expected operators among {}", all.join(", "))
+            })?;
+            symbols.push(symbol);
+            s = &s[symbol.len()..];
+        }
+        Ok(Operators(symbols))
+    }
+}
+
+fn part_x<N: Number>(equations: &[Equation<N>], ops: &[Operator<N>]) -> Result<u128, ParseError> {
+    equations
+        .iter()
+        .filter(|(ans, terms)| solve(*ans, terms, ops).is_some())
+        .try_fold(0u128, |sum, (ans, _)| sum.checked_add((*ans).into()))
+        .ok_or_else(|| ParseError::unlocated("sum of the true equations above 2^128"))
+}
+fn parse(input: &str) -> Result<Calibration, ParseError> {
+    let equations = input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (ans, terms) = split_token(input, line, ":")?;
+            let terms = terms
+                .split_ascii_whitespace()
+                .map(|x| parse_token(input, x))
+                .collect::<Result<Vec<u128>, ParseError>>()?;
+            if terms.is_empty() {
+                return Err(ParseError::at(input, line, "equation without any term"));
+            }
+            Ok((parse_token(input, ans)?, terms))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let narrow = equations
+        .iter()
+        .map(|(ans, terms)| {
+            let terms = terms.iter().map(|&t| u64::try_from(t).ok());
+            Some((u64::try_from(*ans).ok()?, terms.collect::<Option<_>>()?))
+        })
+        .collect::<Option<_>>();
+    let equations = match narrow {
+        Some(narrow) => Equations::Narrow(narrow),
+        None => Equations::Wide(equations),
+    };
+    Ok(Calibration {
+        equations,
+        extra: Vec::new(),
+    })
+}
+/// Operators combining `terms` from left to right into `ans`, if any.
+fn solve<'a, N: Number>(
+    ans: N,
+    terms: &[N],
+    ops: &'a [Operator<N>],
+) -> Option<Vec<&'a Operator<N>>> {
+    if ops.iter().all(|op| op.undo.is_some()) {
+        solve_backwards(ans, terms, ops)
+    } else {
+        let mut solution = Vec::new();
+        solve_forwards(Some(ans), terms[0], &terms[1..], ops, &mut solution).then_some(solution)
+    }
+}
+/// Search going from the answer back to the first term, undoing the
+/// operators with the last terms: most of them have no inverse for a given
+/// value (the answer is not a multiple of the term, or does not end with
+/// its digits), which cuts the search much earlier than going forward.
+fn solve_backwards<'a, N: Number>(
+    ans: N,
+    terms: &[N],
+    ops: &'a [Operator<N>],
+) -> Option<Vec<&'a Operator<N>>> {
+    let (&last, rest) = terms.split_last()?;
+    if rest.is_empty() {
+        return (ans == last).then(Vec::new);
+    }
+    for op in ops {
+        let undo = op.undo.expect("operators with an inverse");
+        let solution = match undo(ans, last) {
+            Undo::Impossible => None,
+            Undo::Value(prev) => solve_backwards(prev, rest, ops).map(|mut solution| {
+                solution.push(op);
+                solution
+            }),
+            // any value of the first terms, as long as it fits in `N`
+            Undo::Any => {
+                let mut solution = Vec::new();
+                solve_forwards(None, rest[0], &rest[1..], ops, &mut solution).then(|| {
+                    solution.push(op);
+                    solution
+                })
+            }
+        };
+        if solution.is_some() {
+            return solution;
+        }
+    }
+    None
+}
+/// Every combination of `ops` from `acc` on, pushing the operators of the
+/// first one giving `ans` (or any value fitting in `N` for `None`) to
+/// `solution`.
+fn solve_forwards<'a, N: Number>(
+    ans: Option<N>,
+    acc: N,
+    terms: &[N],
+    ops: &'a [Operator<N>],
+    solution: &mut Vec<&'a Operator<N>>,
+) -> bool {
+    let Some((&term, rest)) = terms.split_first() else {
+        return ans.is_none_or(|ans| acc == ans);
+    };
+    for op in ops {
+        let Some(next) = (op.apply)(acc, term) else {
+            continue;
+        };
+        solution.push(op);
+        if solve_forwards(ans, next, rest, ops, solution) {
+            return true;
+        }
+        solution.pop();
+    }
+    false
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&parse(EXAMPLE).unwrap()), Ok(3749));
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&parse(EXAMPLE).unwrap()), Ok(11387));
+}
+
+/// Every combination of `ops` tried without pruning.
+#[cfg(test)]
+fn match_brute_force<N: Number>(ans: N, terms: &[N], ops: &[Operator<N>]) -> bool {
+    let n_combinations = ops.len().pow(terms.len() as u32 - 1);
+    (0..n_combinations).any(|mut combination| {
+        let value = terms[1..].iter().try_fold(terms[0], |acc, &t| {
+            let op = ops[combination % ops.len()];
+            combination /= ops.len();
+            (op.apply)(acc, t)
+        });
+        value == Some(ans)
+    })
+}
+
+#[cfg(test)]
+fn symbols<N>(solution: Option<Vec<&Operator<N>>>) -> Option<Vec<&'static str>> {
+    solution.map(|ops| ops.iter().map(|op| op.symbol).collect())
+}
+
+#[test]
+fn test_solve() {
+    let ops = [Operator::<u64>::ADD, Operator::MUL, Operator::CONCAT];
+    assert_eq!(
+        symbols(solve(3267, &[81, 40, 27], &ops)),
+        Some(vec!["*", "+"])
+    );
+    assert_eq!(
+        symbols(solve(7290, &[6, 8, 6, 15], &ops)),
+        Some(vec!["*", "||", "*"])
+    );
+    assert_eq!(solve(7290, &[6, 8, 6, 15], &ops[..2]), None);
+    assert_eq!(symbols(solve(0, &[7, 3, 0], &ops)), Some(vec!["+", "*"]));
+    assert_eq!(symbols(solve(5, &[5], &ops)), Some(vec![]));
+    assert_eq!(
+        symbols(solve(3u64, &[7, 5, 1], &[Operator::SUB, Operator::XOR])),
+        Some(vec!["-", "^"])
+    );
+}
+#[test]
+fn test_operators() {
+    let input = "12: 15 3\n6: 3 5\n";
+    assert_eq!(part2(&parse(input).unwrap()), Ok(0));
+    let calibration = Day07::parse_with(input, &"ops=-^".parse().unwrap()).unwrap();
+    assert_eq!(calibration.extra, ["-", "^"]);
+    assert_eq!(part1(&calibration), Ok(18));
+    assert_eq!(
+        "+-x".parse::<Operators>().err(),
+        Some("expected operators among `+`, `*`, `||`, `-`, `^`".to_string())
+    );
+    let explained = explain(&parse(EXAMPLE).unwrap());
+    assert!(explained.starts_with("6 of 9 equations true with + * ||\n190 = 10 * 19\n"));
+    assert!(explained.contains("\n7290 = 6 * 8 || 6 * 15\n"));
+}
+#[test]
+fn test_overflow() {
+    let ops = Operator::<u64>::ALL;
+    let (max, big) = (u64::MAX, 10u64.pow(19));
+    for (ans, terms) in [
+        (max, vec![1 << 32, 1 << 32]),
+        (max, vec![max, 2, 1]),
+        (max, vec![1, max]),
+        (big, vec![0, big]),
+        (big, vec![1, big]),
+        (1, vec![big, big]),
+    ] {
+        for ops in [&ops[..3], &ops] {
+            assert_eq!(
+                solve(ans, &terms, ops).is_some(),
+                match_brute_force(ans, &terms, ops),
+                "{ans}: {terms:?}"
+            );
+        }
+    }
+    // the first terms before `* 0` must still fit
+    let forwards = ops.map(|op| Operator { undo: None, ..op });
+    for terms in [[1 << 63, 2, 0], [max, max, 0], [max, 1, 0], [max, 0, 0]] {
+        for (ops, forwards) in [(&ops[..1], &forwards[..1]), (&ops[..3], &forwards[..3])] {
+            let solution = solve(0, &terms, ops);
+            assert_eq!(solution.is_some(), solve(0, &terms, forwards).is_some());
+            assert_eq!(solution.is_some(), match_brute_force(0, &terms, ops));
+            if let Some(solution) = solution {
+                let value = terms[1..]
+                    .iter()
+                    .zip(&solution)
+                    .try_fold(terms[0], |acc, (&t, op)| (op.apply)(acc, t));
+                assert_eq!(value, Some(0), "{terms:?}");
+            }
+        }
+    }
+    assert_eq!(
+        symbols(solve(0, &[1 << 63, 2, 0], &ops[..3])),
+        Some(vec!["+", "*"])
+    );
+    assert_eq!(solve(0, &[max, max, 0], &ops[..3]), None);
+    // 2^64 does not fit in 64 bits
+    let calibration = parse("18446744073709551616: 4294967296 4294967296").unwrap();
+    assert!(matches!(calibration.equations, Equations::Wide(_)));
+    assert_eq!(part1(&calibration), Ok(1 << 64));
+    // 2^63 * 2 does not either, along the way
+    let input = "18446744073709551615: 9223372036854775808 2 1";
+    let params = "ops=-,wide=true".parse().unwrap();
+    assert_eq!(
+        part1(&Day07::parse_with(input, &params).unwrap()),
+        Ok(u64::MAX as u128)
+    );
+    let params = "ops=-".parse().unwrap();
+    assert_eq!(part1(&Day07::parse_with(input, &params).unwrap()), Ok(0));
+    let max = u128::MAX;
+    assert_eq!(
+        part1(&parse(&format!("{max}: {max}\n1: 1")).unwrap()),
+        Err(ParseError::unlocated(
+            "sum of the true equations above 2^128"
+        ))
+    );
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_match_equation(
+        terms in proptest::collection::vec(0..100u64, 1..7),
+        choices in proptest::collection::vec(0..3usize, 6),
+        offset in proptest::prop_oneof![proptest::strategy::Just(0u64), 0..10u64],
+    ) {
+        let ops = Operator::ALL;
+        // half of the answers are reachable by construction
+        let ans = terms[1..]
+            .iter()
+            .zip(&choices)
+            .fold(terms[0], |acc, (&t, &i)| (ops[i].apply)(acc, t).unwrap())
+            + offset;
+        let forwards = [
+            Operator::ADD,
+            Operator::MUL,
+            Operator { undo: None, ..Operator::CONCAT },
+        ];
+        for ops in [&ops[..2], &ops[..3], &ops, &forwards] {
+            let solution = solve(ans, &terms, ops);
+            proptest::prop_assert_eq!(solution.is_some(), match_brute_force(ans, &terms, ops));
+            if let Some(solution) = solution {
+                let value = terms[1..]
+                    .iter()
+                    .zip(&solution)
+                    .try_fold(terms[0], |acc, (&t, op)| (op.apply)(acc, t));
+                proptest::prop_assert_eq!(value, Some(ans));
+            }
+        }
+    }
+}
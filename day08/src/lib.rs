@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Coord, Grid};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(input, |c| {
+            (c == b'.' || c.is_ascii_alphanumeric()).then_some(c)
+        })
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(grid: &Grid<u8>) -> usize {
+    part_x(grid, |c1, c2, mat| {
+        let dist = c2 - c1;
+        mat.set(c1 - dist, b'#');
+        mat.set(c2 + dist, b'#');
+    })
+}
+fn part2(grid: &Grid<u8>) -> usize {
+    part_x(grid, |c1, c2, mat| {
+        let dist = normalize(c2 - c1);
+        let mut tag_antinodes = |init: Coord, dir: Coord| {
+            let mut cur = init;
+            while mat.set(cur, b'#') {
+                cur += dir;
+            }
+        };
+        tag_antinodes(c1, -dist);
+        tag_antinodes(c1, dist);
+    })
+}
+fn part_x<F: FnMut(Coord, Coord, &mut Grid<u8>)>(grid: &Grid<u8>, mut func: F) -> usize {
+    let mut mat = grid.clone();
+    let mut antenas = HashMap::new();
+    for coord in mat.coords() {
+        let antena = mat[coord];
+        if antena != b'.' {
+            antenas
+                .entry(antena)
+                .or_insert(Vec::<Coord>::new())
+                .push(coord);
+        }
+    }
+    for (_, coords) in antenas {
+        for (i, &c1) in coords.iter().enumerate() {
+            for &c2 in coords.iter().take(i) {
+                func(c1, c2, &mut mat);
+            }
+        }
+    }
+    mat.iter().filter(|&&c| c == b'#').count()
+}
+fn normalize(coord: Coord) -> Coord {
+    let [x, y] = coord.0;
+    let (min, max) = if x.abs() > y.abs() { (y, x) } else { (x, y) };
+    if min != 0 && max % min == 0 {
+        Coord([x / min, y / min])
+    } else {
+        coord
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&Day08::parse(EXAMPLE).unwrap()), 14);
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Day08::parse(EXAMPLE).unwrap()), 34);
+}
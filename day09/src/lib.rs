@@ -0,0 +1,128 @@
+use std::iter::repeat_n;
+
+use aoc_core::{ParseError, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    /// File id of every block, [`EMPTY`] for free space.
+    type Input<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+pub const EMPTY: usize = usize::MAX;
+fn part1(files: &[usize]) -> usize {
+    let compact1 = |files: &mut Vec<usize>| {
+        let mut start = 0;
+        loop {
+            while files.last() == Some(&EMPTY) {
+                files.pop();
+            }
+            if start >= files.len() {
+                break;
+            }
+            if files[start] == EMPTY {
+                // the last block is a file, so not the one at `start`
+                files.swap_remove(start);
+            }
+            start += 1;
+        }
+    };
+    part_x(files, compact1)
+}
+
+fn part2(files: &[usize]) -> usize {
+    let compact2 = |files: &mut Vec<usize>| {
+        let Some(&last_file) = files.iter().rev().find(|&&id| id != EMPTY) else {
+            return;
+        };
+        // first free block and start of the last file moved or not, the
+        // files left to move being before it
+        let (mut start, mut end) = (0, files.len());
+        for current_file in (0..=last_file).rev() {
+            let Some(last) = files[..end].iter().rposition(|&id| id == current_file) else {
+                // empty file
+                continue;
+            };
+            let len = files[..=last]
+                .iter()
+                .rev()
+                .take_while(|&&id| id == current_file)
+                .count();
+            end = last + 1 - len;
+            while start < end && files[start] != EMPTY {
+                start += 1;
+            }
+            if start + len > end {
+                continue;
+            }
+            if let Some(start_i) =
+                (start..=(end - len)).find(|&i| files[i..(i + len)].iter().all(|&id| id == EMPTY))
+            {
+                files[start_i..(start_i + len)].fill(current_file);
+                files[end..(end + len)].fill(EMPTY);
+            }
+        }
+    };
+    part_x(files, compact2)
+}
+
+fn part_x(files: &[usize], mut compact: impl FnMut(&mut Vec<usize>)) -> usize {
+    let mut files = files.to_vec();
+    compact(&mut files);
+    files
+        .into_iter()
+        .enumerate()
+        .map(|(pos, id)| if id != EMPTY { pos * id } else { 0 })
+        .sum()
+}
+
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let disk_map = input.trim();
+    let mut files = Vec::new();
+    for (i, c) in disk_map.bytes().enumerate() {
+        if !c.is_ascii_digit() {
+            let msg = format!("unexpected character `{}`", c as char);
+            return Err(ParseError::at(input, &disk_map[i..], msg));
+        }
+        let id = if i % 2 == 0 { i / 2 } else { EMPTY };
+        files.extend(repeat_n(id, (c - b'0') as usize));
+    }
+    if files.iter().all(|&id| id == EMPTY) {
+        return Err(ParseError::eof(input, "disk map without any file block"));
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "2333133121414131402";
+#[test]
+fn test1() {
+    assert_eq!(part1(&parse(EXAMPLE).unwrap()), 1928);
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&parse(EXAMPLE).unwrap()), 2858);
+}
+#[test]
+fn test_short_maps() {
+    let files = parse("12345").unwrap();
+    assert_eq!((part1(&files), part2(&files)), (60, 132));
+    let files = parse("1312").unwrap();
+    assert_eq!((part1(&files), part2(&files)), (1, 1));
+    let files = parse("0312").unwrap();
+    assert_eq!((part1(&files), part2(&files)), (0, 0));
+    let files = parse("90").unwrap();
+    assert_eq!((part1(&files), part2(&files)), (0, 0));
+}
@@ -0,0 +1,74 @@
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Coord, Grid};
+use aoc_search::Search;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(input, |c| c.is_ascii_digit().then(|| c - b'0'))
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(g: &Grid<u8>) -> usize {
+    part_x(g, |search| {
+        let summits = search.visited().iter().filter(|&&i| g[i] == 9);
+        summits.count()
+    })
+}
+/// Trails from every trailhead at once, each one being a best path.
+fn part2(g: &Grid<u8>) -> usize {
+    let search = trails(g, trailheads(g));
+    let counts = search.count_paths();
+    let summits = search.visited().iter().filter(|&&i| g[i] == 9);
+    summits.map(|&i| counts[i] as usize).sum()
+}
+fn part_x<F: FnMut(&Search<usize>) -> usize>(g: &Grid<u8>, mut score_func: F) -> usize {
+    trailheads(g).map(|i| score_func(&trails(g, [i]))).sum()
+}
+fn trailheads(g: &Grid<u8>) -> impl Iterator<Item = usize> + '_ {
+    (0..g.len()).filter(|&i| g[i] == 0)
+}
+/// Search of the cells on a hiking trail from the trailheads `starts`: every
+/// trail is a best path, all of its steps going up by one.
+fn trails(g: &Grid<u8>, starts: impl IntoIterator<Item = usize>) -> Search<usize> {
+    let neighbors = |i: usize| {
+        let coord: Coord = g.pos(i);
+        let height = g[coord];
+        g.neighbors4(coord)
+            .filter(move |&nei| g[nei].checked_sub(height).is_some_and(|dh| dh == 1))
+            .filter_map(|nei| g.idx(nei))
+    };
+    aoc_search::bfs(g.len(), starts, neighbors, |_| false)
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&Day10::parse(EXAMPLE).unwrap()), 36);
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Day10::parse(EXAMPLE).unwrap()), 81);
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use aoc_core::{parse_token, ParseError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .split_ascii_whitespace()
+            .map(|x| parse_token(input, x))
+            .collect()
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(stones: &[u64]) -> u64 {
+    part_x(stones, 25)
+}
+fn part2(stones: &[u64]) -> u64 {
+    part_x(stones, 75)
+}
+fn part_x(stones: &[u64], nblinks: u8) -> u64 {
+    let mut table0 = HashMap::<u64, u64>::new();
+    let mut table1 = HashMap::<u64, u64>::new();
+    for &x in stones {
+        *table0.entry(x).or_insert(0) += 1;
+    }
+    let mut cur0 = true;
+    for _i in 0..nblinks {
+        let (src, dst) = if cur0 {
+            (&table0, &mut table1)
+        } else {
+            (&table1, &mut table0)
+        };
+        blink(src, dst);
+        cur0 = !cur0;
+    }
+    let last_step = if cur0 { table0 } else { table1 };
+    last_step.into_values().sum()
+}
+fn blink(src: &HashMap<u64, u64>, dst: &mut HashMap<u64, u64>) {
+    dst.clear();
+    for (&x, &count) in src.iter() {
+        let digits = ndigits(x);
+        match x {
+            0 => {
+                *dst.entry(1).or_insert(0) += count;
+            }
+            x if digits.is_multiple_of(2) => {
+                let power = 10u64.pow(digits / 2);
+                *dst.entry(x / power).or_insert(0) += count;
+                *dst.entry(x % power).or_insert(0) += count;
+            }
+            x => {
+                *dst.entry(x * 2024).or_insert(0) += count;
+            }
+        }
+    }
+}
+fn ndigits(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+#[cfg(test)]
+const EXAMPLE: &str = "125 17";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&Day11::parse(EXAMPLE).unwrap()), 55312);
+}
+
+#[cfg(test)]
+fn part_x_brute_force(stones: &[u64], nblinks: u8) -> u64 {
+    let mut stones = stones.to_vec();
+    for _ in 0..nblinks {
+        stones = stones
+            .into_iter()
+            .flat_map(|x| {
+                let digits = ndigits(x);
+                match x {
+                    0 => vec![1],
+                    x if digits.is_multiple_of(2) => {
+                        let s = x.to_string();
+                        let (left, right) = s.split_at(s.len() / 2);
+                        vec![left.parse().unwrap(), right.parse().unwrap()]
+                    }
+                    x => vec![x * 2024],
+                }
+            })
+            .collect();
+    }
+    stones.len() as u64
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_blink(stones in proptest::collection::vec(0..100_000u64, 0..6), nblinks in 0..15u8) {
+        proptest::prop_assert_eq!(part_x(&stones, nblinks), part_x_brute_force(&stones, nblinks));
+    }
+}
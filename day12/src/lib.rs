@@ -0,0 +1,127 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Coord, Grid};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(input, |c| c.is_ascii_uppercase().then_some(c))
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(garden: &Grid<u8>) -> usize {
+    part_x(garden, |borders| borders.len())
+}
+fn part2(garden: &Grid<u8>) -> usize {
+    part_x(garden, |borders| {
+        let mut nsides = 0;
+        while let Some(&(coord, dir)) = borders.iter().next() {
+            borders.remove(&(coord, dir));
+            let orth_dir = dir.rotate_right();
+            for orth in [orth_dir, -orth_dir] {
+                let mut nei = (coord + orth, dir);
+                while borders.contains(&nei) {
+                    borders.remove(&nei);
+                    nei.0 += orth;
+                }
+            }
+            nsides += 1;
+        }
+        nsides
+    })
+}
+fn part_x<F>(garden: &Grid<u8>, f: F) -> usize
+where
+    F: Fn(&mut HashSet<(Coord, Coord)>) -> usize,
+{
+    let mut g = garden.clone();
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    let mut borders = HashSet::new();
+    let mut total = 0;
+    for coord in g.coords() {
+        let letter = g[coord];
+        if letter == TAGGED {
+            continue;
+        }
+        let (score, area) = tag_region(
+            &mut g,
+            coord,
+            letter,
+            &mut queue,
+            &mut visited,
+            &mut borders,
+            &f,
+        );
+        total += score * area;
+    }
+    total
+}
+
+/// Marker of plots already counted in a region.
+const TAGGED: u8 = 0;
+
+fn tag_region<F: Fn(&mut HashSet<(Coord, Coord)>) -> usize>(
+    g: &mut Grid<u8>,
+    start: Coord,
+    letter: u8,
+    queue: &mut VecDeque<Coord>,
+    visited: &mut HashSet<Coord>,
+    borders: &mut HashSet<(Coord, Coord)>,
+    score_fn: F,
+) -> (usize, usize) {
+    g[start] = TAGGED;
+    queue.push_front(start);
+    visited.insert(start);
+    while let Some(coord) = queue.pop_back() {
+        for dir in Coord::DIRECTIONS {
+            let nei = coord + dir;
+            if g.get(nei).is_some_and(|&nei_l| nei_l == letter) {
+                g[nei] = TAGGED;
+                queue.push_front(nei);
+                visited.insert(nei);
+            }
+            if !visited.contains(&nei) {
+                borders.insert((nei, dir));
+            }
+        }
+    }
+    let area = visited.len();
+    let score = score_fn(borders);
+    visited.clear();
+    borders.clear();
+    (score, area)
+}
+#[cfg(test)]
+const EXAMPLE: &str = "
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+#[test]
+fn test1() {
+    assert_eq!(part1(&Day12::parse(EXAMPLE).unwrap()), 1930);
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Day12::parse(EXAMPLE).unwrap()), 1206);
+}
@@ -0,0 +1,182 @@
+use aoc_core::{parse_token, split_token, strip_token, ParseError, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input<'a> = Vec<Machine>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(machines: &[Machine]) -> i64 {
+    part_x(machines, 0)
+}
+fn part2(machines: &[Machine]) -> i64 {
+    part_x(machines, 10000000000000)
+}
+fn part_x(machines: &[Machine], offset: i64) -> i64 {
+    machines
+        .iter()
+        .filter_map(|&[coord_a, coord_b, target]| {
+            min_cost(coord_a, coord_b, target.map(|x| x + offset))
+        })
+        .sum()
+}
+
+/// Moves of the buttons A and B and position of the prize.
+pub type Machine = [[i64; 2]; 3];
+
+fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let mut machines = Vec::new();
+    for paragraph in input.trim().split("\n\n") {
+        let mut lines = paragraph.lines();
+        let mut coord = |prefix, sep| {
+            let line = lines.next().ok_or_else(|| {
+                let msg = format!("expected a line starting with `{prefix}`");
+                ParseError::at(input, &paragraph[paragraph.len()..], msg)
+            })?;
+            let (x, y) = split_token(input, strip_token(input, line, prefix)?, sep)?;
+            Ok::<_, ParseError>([parse_token(input, x)?, parse_token(input, y)?])
+        };
+        machines.push([
+            coord("Button A: X+", ", Y+")?,
+            coord("Button B: X+", ", Y+")?,
+            coord("Prize: X=", ", Y=")?,
+        ]);
+    }
+    Ok(machines)
+}
+
+fn min_cost(coord_a: [i64; 2], coord_b: [i64; 2], target: [i64; 2]) -> Option<i64> {
+    let [x_a, y_a] = coord_a;
+    let [x_b, y_b] = coord_b;
+    let [x, y] = target;
+    // 2 equations 2 unknowns => gaussian elimination
+    // x = a * x_a + b * x_b
+    // y = a * y_a + b * y_b
+    let num_b = x_a * y - x * y_a;
+    let denum_b = x_a * y_b - x_b * y_a;
+    if denum_b == 0 {
+        return min_cost_colinear(coord_a, coord_b, target);
+    }
+    // keep only the integer solutions
+    if num_b % denum_b != 0 {
+        return None;
+    }
+    let b = num_b / denum_b;
+    let (pos, move_a, move_b) = if x_a != 0 {
+        (x, x_a, x_b)
+    } else {
+        (y, y_a, y_b)
+    };
+    let num_a = pos - move_b * b;
+    if num_a % move_a != 0 {
+        return None;
+    }
+    let a = num_a / move_a;
+    (a >= 0 && b >= 0).then_some(3 * a + b)
+}
+/// Buttons moving along the same line: the target must be on it too, then the
+/// button with the most distance per token is pressed as much as possible.
+fn min_cost_colinear(coord_a: [i64; 2], coord_b: [i64; 2], target: [i64; 2]) -> Option<i64> {
+    let cross = |[x1, y1]: [i64; 2], [x2, y2]: [i64; 2]| x1 * y2 - x2 * y1;
+    if cross(coord_a, target) != 0 || cross(coord_b, target) != 0 {
+        return None;
+    }
+    let axis = if coord_a[0] != 0 || coord_b[0] != 0 {
+        0
+    } else {
+        1
+    };
+    let (move_a, move_b, pos) = (coord_a[axis], coord_b[axis], target[axis]);
+    // presses of `other` that leave a multiple of `best` to go, fewer than
+    // `best` presses are enough to try every remainder
+    let fewest = |best: i64, other: i64| {
+        (0..=best.max(1)).find_map(|n| {
+            let rest = pos - n * other;
+            if rest < 0 || best == 0 {
+                return (rest == 0).then_some((n, 0));
+            }
+            (rest % best == 0).then_some((n, rest / best))
+        })
+    };
+    if 3 * move_b >= move_a {
+        fewest(move_b, move_a).map(|(a, b)| 3 * a + b)
+    } else {
+        fewest(move_a, move_b).map(|(b, a)| 3 * a + b)
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&parse(EXAMPLE).unwrap()), 480);
+}
+
+#[cfg(test)]
+fn min_cost_brute_force(coord_a: [i64; 2], coord_b: [i64; 2], target: [i64; 2]) -> Option<i64> {
+    let presses =
+        |[x, y]: [i64; 2]| (0..).take_while(move |n| n * x <= target[0] && n * y <= target[1]);
+    presses(coord_a)
+        .flat_map(|a| {
+            presses(coord_b)
+                .filter(move |b| (0..2).all(|i| a * coord_a[i] + b * coord_b[i] == target[i]))
+                .map(move |b| 3 * a + b)
+        })
+        .min()
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_min_cost(
+        coord_a in [1..30i64, 1..30i64],
+        coord_b in [1..30i64, 1..30i64],
+        presses in [0..40i64, 0..40i64],
+        colinear in 0..3i64,
+    ) {
+        // button B is often a multiple of A to get several solutions
+        let coord_b = match colinear {
+            0 => coord_b,
+            k => coord_a.map(|x| x * k),
+        };
+        let target = [0, 1].map(|i| presses[0] * coord_a[i] + presses[1] * coord_b[i]);
+        proptest::prop_assert_eq!(
+            min_cost(coord_a, coord_b, target),
+            min_cost_brute_force(coord_a, coord_b, target)
+        );
+        let target = target.map(|x| x + 1);
+        proptest::prop_assert_eq!(
+            min_cost(coord_a, coord_b, target),
+            min_cost_brute_force(coord_a, coord_b, target)
+        );
+    }
+}
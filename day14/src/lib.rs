@@ -0,0 +1,206 @@
+use aoc_core::{parse_token, split_token, strip_token, Params, ParseError, Solution};
+use aoc_grid::{Coord, Grid};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input<'a> = Bathroom;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    const PARAMS: &'static [&'static str] = &["width", "height"];
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Bathroom::new(input, params.get("width")?, params.get("height")?)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        part2(input)
+            .ok_or_else(|| ParseError::unlocated(format!("no tree within {MAX_SECONDS} seconds")))
+    }
+    fn explain(input: &Self::Input<'_>) -> Option<String> {
+        Some(match find_tree(input) {
+            Some((second, grid)) => format!("tree after {second} seconds\n{grid}"),
+            None => format!("no tree within {MAX_SECONDS} seconds\n"),
+        })
+    }
+}
+
+fn part1(bathroom: &Bathroom) -> usize {
+    let size_mid = bathroom.size / 2;
+    let mut quadrants = [0, 0, 0, 0];
+    let robots = bathroom.robots_n_steps(100);
+    for [next_pos, _] in robots {
+        if next_pos.0[0] == size_mid.0[0] || next_pos.0[1] == size_mid.0[1] {
+            continue;
+        }
+        let quad = next_pos / (size_mid + 1);
+        let i = quad.0[0] + 2 * quad.0[1];
+        quadrants[i as usize] += 1;
+    }
+    quadrants.into_iter().product()
+}
+fn part2(bathroom: &Bathroom) -> Option<usize> {
+    find_tree(bathroom).map(|(second, _)| second)
+}
+/// Seconds after which [`find_tree`] gives up.
+const MAX_SECONDS: usize = 10_000;
+/// First second with a tree, a full square of robots near the middle, and
+/// the area at that second.
+fn find_tree(bathroom: &Bathroom) -> Option<(usize, Grid<u8>)> {
+    const N: isize = 5;
+    let size = bathroom.size;
+    let [x, y] = size.0;
+    let (start_x, end_x) = (x * 2 / 5, x * 3 / 5);
+    let (start_y, end_y) = (y * 2 / 5, y * 3 / 5);
+    let mut robots = bathroom.robots_n_steps(0);
+    for i in 0..MAX_SECONDS {
+        let mut grid = Grid::new(x as usize, y as usize, b' ');
+        for &[pos, _] in robots.iter() {
+            grid[pos] = b'x';
+        }
+        if (start_y..end_y)
+            .flat_map(|y| (start_x..end_x).map(move |x| Coord::new(x, y)))
+            .any(|corner| {
+                (0..N)
+                    .flat_map(|dy| (0..N).map(move |dx| Coord::new(dx, dy)))
+                    .all(|delta| grid.get(corner + delta) == Some(&b'x'))
+            })
+        {
+            return Some((i, grid));
+        }
+        robots.iter_mut().for_each(|[pos, vel]| {
+            *pos = (((*pos + *vel) % size) + size) % size;
+        })
+    }
+    None
+}
+
+/// Robots (position and velocity) moving in an area of `size` tiles.
+pub struct Bathroom {
+    size: Coord,
+    robots: Vec<[Coord; 2]>,
+}
+
+impl Bathroom {
+    /// Dimensions not given are inferred from the robots furthest away.
+    fn new(input: &str, width: Option<isize>, height: Option<isize>) -> Result<Self, ParseError> {
+        let mut robots = Vec::new();
+        let mut lines = Vec::new();
+        for line in input.trim().lines() {
+            let (pos, vel) = split_token(input, strip_token(input, line, "p=")?, " v=")?;
+            robots.push([parse_coord(input, pos)?, parse_coord(input, vel)?]);
+            lines.push(line);
+        }
+        let inferred = |axis: usize| robots.iter().map(|r| r[0].0[axis] + 1).max();
+        let size = match (
+            width.or_else(|| inferred(0)),
+            height.or_else(|| inferred(1)),
+        ) {
+            (Some(x), Some(y)) if x > 0 && y > 0 => Coord([x, y]),
+            _ => return Err(ParseError::eof(input, "cannot tell the size of the area")),
+        };
+        for (&[pos, _], line) in robots.iter().zip(lines) {
+            if pos != pos % size || pos.0.iter().any(|&x| x < 0) {
+                let msg = format!("robot outside of the {}x{} area", size.0[0], size.0[1]);
+                return Err(ParseError::at(input, line, msg));
+            }
+        }
+        Ok(Bathroom { size, robots })
+    }
+    fn robots_n_steps(&self, n: isize) -> Vec<[Coord; 2]> {
+        let size = self.size;
+        self.robots
+            .iter()
+            .map(|&[pos, vel]| {
+                let mut next_pos = (pos + (vel * n)) % size;
+                if next_pos.0[0] < 0 || next_pos.0[1] < 0 {
+                    next_pos = (next_pos + size) % size;
+                }
+                [next_pos, vel]
+            })
+            .collect()
+    }
+}
+
+fn parse_coord(input: &str, s: &str) -> Result<Coord, ParseError> {
+    let (x, y) = split_token(input, s, ",")?;
+    Ok(Coord([parse_token(input, x)?, parse_token(input, y)?]))
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+/// Robots forming a 5x5 square in the middle of an 11x7 area at the 6th second.
+#[cfg(test)]
+const EXAMPLE_TREE: &str = "
+p=5,3 v=-2,1
+p=10,0 v=1,-2
+p=1,3 v=-1,1
+p=7,4 v=0,2
+p=2,6 v=1,-3
+p=9,0 v=1,-3
+p=9,3 v=3,0
+p=1,4 v=-1,1
+p=8,1 v=-2,-2
+p=7,3 v=2,0
+p=9,0 v=1,3
+p=10,4 v=1,0
+p=6,6 v=0,2
+p=0,2 v=3,-2
+p=9,6 v=-2,2
+p=5,1 v=-2,3
+p=10,5 v=1,0
+p=5,2 v=2,-3
+p=6,1 v=2,3
+p=4,3 v=-3,-2
+p=8,0 v=3,1
+p=1,5 v=-3,-1
+p=10,3 v=3,-3
+p=0,2 v=3,3
+p=3,6 v=-1,0
+";
+
+#[test]
+fn test1() {
+    let bathroom = Day14::parse(EXAMPLE).unwrap();
+    assert_eq!(bathroom.size, Coord::new(11, 7));
+    assert_eq!(part1(&bathroom), 12);
+}
+#[test]
+fn test2() {
+    let params = "width=11,height=7".parse().unwrap();
+    assert_eq!(
+        Day14::part2(&Day14::parse_with(EXAMPLE_TREE, &params).unwrap()),
+        Ok(6)
+    );
+    assert_eq!(
+        Day14::part2(&Day14::parse_with(EXAMPLE, &params).unwrap()),
+        Err(ParseError::unlocated("no tree within 10000 seconds"))
+    );
+    let explained = Day14::explain(&Day14::parse_with(EXAMPLE_TREE, &params).unwrap()).unwrap();
+    assert!(explained.starts_with("tree after 6 seconds\n"));
+    assert!(explained.contains("xxxxx"));
+    let params = "width=5,height=7".parse().unwrap();
+    assert_eq!(
+        Day14::parse_with(EXAMPLE, &params).err(),
+        Some(ParseError::new(3, 1, "robot outside of the 5x7 area"))
+    );
+}
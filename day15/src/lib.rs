@@ -0,0 +1,338 @@
+use std::collections::VecDeque;
+
+use aoc_core::{split_token, ParseError, Solution};
+use aoc_grid::{Coord, Grid};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input<'a> = Warehouse;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Warehouse::new(input)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        part2(input)
+    }
+}
+
+fn part1(warehouse: &Warehouse) -> Result<usize, ParseError> {
+    part_x(warehouse.clone(), b'O')
+}
+fn part2(warehouse: &Warehouse) -> Result<usize, ParseError> {
+    part_x(warehouse.widen(), b'[')
+}
+fn part_x(mut g: Warehouse, target: u8) -> Result<usize, ParseError> {
+    let mut visited = VecDeque::new();
+    while g.move_robot(&mut visited)? {}
+    let sum = g
+        .grid
+        .coords()
+        .filter(|&c: &Coord| g.grid[c] == target)
+        .map(|c| {
+            let [x, y] = c.0;
+            (100 * y + x) as usize
+        })
+        .sum();
+    Ok(sum)
+}
+
+#[derive(Clone)]
+pub struct Warehouse {
+    grid: Grid<u8>,
+    current: Coord,
+    instructions: VecDeque<u8>,
+}
+
+impl Warehouse {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let (p1, p2) = split_token(input, input.trim(), "\n\n")?;
+        let width = p1.lines().next().map_or(0, str::len);
+        for line in p1.lines() {
+            if line.len() != width {
+                let msg = format!("line of {} cells in a grid of width {width}", line.len());
+                return Err(ParseError::at(input, line, msg));
+            }
+            check_chars(input, line, b"#.O@")?;
+        }
+        if let Some((i, _)) = p1.match_indices('@').nth(1) {
+            return Err(ParseError::at(
+                input,
+                &p1[i..],
+                "second robot `@` in the warehouse",
+            ));
+        }
+        for line in p2.lines() {
+            check_chars(input, line, b"<>^v")?;
+        }
+        let ny = p1.lines().count();
+        let grid = p1.lines().flat_map(str::bytes).collect::<Vec<u8>>();
+        let grid = Grid::from_vec(width, ny, grid);
+        if let Some(c) = grid.border().find(|&c: &Coord| grid[c] != b'#') {
+            let [x, y] = c.0.map(|x| x as usize);
+            let line = p1.lines().nth(y).unwrap_or_default();
+            return Err(ParseError::at(
+                input,
+                &line[x..],
+                "warehouse not closed by walls",
+            ));
+        }
+        let init = grid
+            .position(|&c| c == b'@')
+            .ok_or_else(|| ParseError::at(input, p2, "missing robot `@` in the warehouse"))?;
+        let mut g = Warehouse {
+            grid,
+            current: init,
+            instructions: p2
+                .lines()
+                .flat_map(|s| s.as_bytes().iter().copied())
+                .collect(),
+        };
+        g.set(&init, b'.');
+        Ok(g)
+    }
+    /// Same warehouse with every tile twice as wide, boxes becoming `[]`.
+    fn widen(&self) -> Self {
+        let grid = self
+            .grid
+            .iter()
+            .flat_map(|&x| match x {
+                b'#' => [b'#', b'#'],
+                b'O' => [b'[', b']'],
+                _ => [b'.', b'.'],
+            })
+            .collect::<Vec<u8>>();
+        Warehouse {
+            grid: Grid::from_vec(2 * self.grid.nx(), self.grid.ny(), grid),
+            current: Coord::new(2 * self.current.x(), self.current.y()),
+            instructions: self.instructions.clone(),
+        }
+    }
+    fn get(&self, coord: &Coord) -> Option<u8> {
+        self.grid.get(*coord).copied()
+    }
+    fn set(&mut self, coord: &Coord, chr: u8) {
+        self.grid.set(*coord, chr);
+    }
+    /// Run the next instruction, false once they are all done.
+    fn move_robot(&mut self, visited: &mut VecDeque<Coord>) -> Result<bool, ParseError> {
+        let Some(instr) = self.instructions.pop_front() else {
+            return Ok(false);
+        };
+        self.push(direction(instr), visited)
+            .ok_or_else(|| ParseError::unlocated("robot or box pushed out of the warehouse"))?;
+        Ok(true)
+    }
+    /// Move the robot in `dir`, pushing the boxes in the way, or `None` when
+    /// something would leave the grid.
+    fn push(&mut self, dir: Coord, visited: &mut VecDeque<Coord>) -> Option<()> {
+        let next_pos = self.current + dir;
+        let object = self.get(&next_pos)?;
+        match object {
+            b'.' => {
+                self.current = next_pos;
+            }
+            b'#' => {}
+            b'O' => {
+                // part 1 box are 1 char
+                let mut box_pos = next_pos;
+                while self.get(&box_pos)? == b'O' {
+                    box_pos += dir;
+                }
+                if self.get(&box_pos)? == b'#' {
+                    return Some(());
+                }
+                self.set(&box_pos, b'O');
+                self.set(&next_pos, b'.');
+                self.current = next_pos;
+            }
+            object if dir.0[0] != 0 => {
+                // part 2 box is [] and move horizontal
+                let mut box_pos = next_pos;
+                let mut next_char = object;
+                while next_char == b'[' || next_char == b']' {
+                    box_pos += dir;
+                    debug_assert_eq!(self.get(&box_pos)?, closing_char(next_char));
+                    box_pos += dir;
+                    next_char = self.get(&box_pos)?;
+                }
+                if self.get(&box_pos)? == b'#' {
+                    return Some(());
+                }
+                let mut box_pos = next_pos;
+                let mut next_char = object;
+                while next_char == b'[' || next_char == b']' {
+                    self.set(&box_pos, closing_char(next_char));
+                    box_pos += dir;
+                    next_char = self.get(&box_pos)?;
+                }
+                self.set(&box_pos, closing_char(object));
+                self.set(&next_pos, b'.');
+                self.current = next_pos;
+            }
+            _ => {
+                // part 2 box is [] and move vertical
+                // complex staircases should move all at once
+                visited.clear();
+                if self.rec_move(next_pos, dir, visited)? {
+                    for k in visited.iter() {
+                        self.set(&(*k + dir), self.get(k)?);
+                        self.set(k, b'.');
+                    }
+                    self.current = next_pos;
+                }
+            }
+        }
+        Some(())
+    }
+    /// Whether the tile at `pos` can move in `dir` with the boxes it pushes,
+    /// which are added to `visited`, or `None` when they would leave the grid.
+    fn rec_move(&self, pos: Coord, dir: Coord, visited: &mut VecDeque<Coord>) -> Option<bool> {
+        if visited.contains(&pos) {
+            return Some(true);
+        }
+        Some(match self.get(&pos)? {
+            b'#' => false,
+            b'.' => true,
+            bracket => {
+                let next = pos + dir;
+                let orth = closing_direction(bracket);
+                let next2 = next + orth;
+                if self.rec_move(next, dir, visited)? && self.rec_move(next2, dir, visited)? {
+                    visited.push_back(pos);
+                    visited.push_back(pos + orth);
+                    true
+                } else {
+                    false
+                }
+            }
+        })
+    }
+}
+fn check_chars(input: &str, line: &str, allowed: &[u8]) -> Result<(), ParseError> {
+    match line.bytes().position(|c| !allowed.contains(&c)) {
+        Some(i) => {
+            let msg = format!("unexpected character `{}`", line.as_bytes()[i] as char);
+            Err(ParseError::at(input, &line[i..], msg))
+        }
+        None => Ok(()),
+    }
+}
+fn closing_char(c: u8) -> u8 {
+    match c {
+        b'[' => b']',
+        b']' => b'[',
+        c => panic!("{c} cannot be closed"),
+    }
+}
+fn closing_direction(c: u8) -> Coord {
+    match c {
+        b'[' => Coord([1, 0]),
+        b']' => Coord([-1, 0]),
+        c => panic!("{c} cannot be closed"),
+    }
+}
+fn direction(cmd: u8) -> Coord {
+    Coord(match cmd {
+        b'>' => [1, 0],
+        b'<' => [-1, 0],
+        b'^' => [0, -1],
+        b'v' => [0, 1],
+        cmd => panic!("unsupported cmd {cmd}"),
+    })
+}
+#[cfg(test)]
+const EXAMPLE_LARGE: &str = "
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+#[cfg(test)]
+const EXAMPLE_SMALL: &str = "
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&Warehouse::new(EXAMPLE_SMALL).unwrap()), Ok(2028));
+    assert_eq!(part1(&Warehouse::new(EXAMPLE_LARGE).unwrap()), Ok(10092));
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Warehouse::new(EXAMPLE_LARGE).unwrap()), Ok(9021));
+}
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Warehouse::new("####\n#@x#\n####\n\n<>").err(),
+        Some(ParseError::new(2, 3, "unexpected character `x`"))
+    );
+    assert_eq!(
+        Warehouse::new("####\n#.O#\n####\n\n<>").err(),
+        Some(ParseError::new(5, 1, "missing robot `@` in the warehouse"))
+    );
+    assert_eq!(
+        Warehouse::new("####\n#@.#\n####\n\n<>\n^w").err(),
+        Some(ParseError::new(6, 2, "unexpected character `w`"))
+    );
+    assert_eq!(
+        Warehouse::new("#####\n#@.@#\n#.O.#\n#####\n\n>>v").err(),
+        Some(ParseError::new(2, 4, "second robot `@` in the warehouse"))
+    );
+    assert_eq!(
+        Warehouse::new(".@.\n.O.\n\nv").err(),
+        Some(ParseError::new(1, 1, "warehouse not closed by walls"))
+    );
+    assert_eq!(
+        Warehouse::new("####\n#@O.\n####\n\n>").err(),
+        Some(ParseError::new(2, 4, "warehouse not closed by walls"))
+    );
+}
+#[test]
+fn test_out_of_grid() {
+    // not closed by walls, which `Warehouse::new` rejects
+    let g = Warehouse {
+        grid: Grid::from_vec(3, 1, b".O.".to_vec()),
+        current: Coord::new(0, 0),
+        instructions: VecDeque::from(*b">>>"),
+    };
+    assert_eq!(
+        part1(&g),
+        Err(ParseError::unlocated(
+            "robot or box pushed out of the warehouse"
+        ))
+    );
+}
@@ -0,0 +1,480 @@
+use aoc_core::{Params, ParseError, Solution};
+use aoc_grid::Grid;
+use aoc_search::{FixedDegree, Masks};
+
+type Coord = aoc_grid::Coord<i32>;
+/// Outcome of [`Maze::search`].
+type Search<'a> = aoc_search::Search<usize, Masks<&'a Maze>>;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input<'a> = Maze;
+    type Answer1 = u32;
+    type Answer2 = usize;
+    const PARAMS: &'static [&'static str] = &["forward", "turn", "uturn"];
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let default = CostModel::default();
+        let costs = CostModel {
+            forward: params.get("forward")?.unwrap_or(default.forward),
+            turn: params.get("turn")?.unwrap_or(default.turn),
+            u_turn: params.get("uturn")?.unwrap_or(default.u_turn),
+        };
+        Maze::new(input, costs)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        part1(input).ok_or_else(no_path)
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        part2(input).ok_or_else(no_path)
+    }
+    fn explain(input: &Self::Input<'_>) -> Option<String> {
+        Some(explain(input))
+    }
+}
+
+fn no_path() -> ParseError {
+    ParseError::unlocated("no path from `S` to `E`")
+}
+fn part1(g: &Maze) -> Option<u32> {
+    // only the cost matters, so no need to keep the predecessors
+    let end = g.idx(&g.end);
+    let start = g.state(g.start, g.direction);
+    let neighbors = |state| g.moves(&g.costs, state);
+    aoc_search::dijkstra_cost(4 * g.grid.len(), [start], neighbors, |state| {
+        state / 4 == end
+    })
+}
+fn part2(g: &Maze) -> Option<usize> {
+    let paths = g.best_paths();
+    paths.cost()?;
+    Some(paths.tiles().into_iter().filter(|&tile| tile).count())
+}
+/// Number of best paths listed by [`explain`].
+const EXPLAIN_PATHS: usize = 10;
+/// The first best paths, listed and drawn over the maze.
+fn explain(g: &Maze) -> String {
+    let best_paths = g.best_paths();
+    let Some(best) = best_paths.cost() else {
+        return format!("{}no path from `S` to `E`\n", g.grid);
+    };
+    // one more path than listed, to know whether there are more
+    let paths = best_paths
+        .iter()
+        .take(EXPLAIN_PATHS + 1)
+        .collect::<Vec<_>>();
+    let mut out = best_paths.render();
+    out += &match paths.len() {
+        n if n > EXPLAIN_PATHS => format!("more than {EXPLAIN_PATHS} best paths of cost {best}\n"),
+        n => format!("{n} best paths of cost {best}\n"),
+    };
+    for path in paths.iter().take(EXPLAIN_PATHS) {
+        // runs of the same action, `F4` for 4 steps forward
+        let mut runs: Vec<(char, usize)> = Vec::new();
+        for step in path.iter().skip(1) {
+            match runs.last_mut() {
+                Some((action, n)) if *action == step.action.symbol() => *n += 1,
+                _ => runs.push((step.action.symbol(), 1)),
+            }
+        }
+        let runs = runs.into_iter().map(|(action, n)| match n {
+            1 => action.to_string(),
+            n => format!("{action}{n}"),
+        });
+        out += &format!("  {}\n", runs.collect::<Vec<_>>().join(" "));
+    }
+    if paths.len() > EXPLAIN_PATHS {
+        out += "  ...\n";
+    }
+    out
+}
+
+/// What the reindeer does to get to a [`Step`] of a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Start,
+    Forward,
+    TurnLeft,
+    TurnRight,
+}
+
+impl Action {
+    /// `F`, `L` or `R` (and `S` for the start).
+    pub fn symbol(self) -> char {
+        match self {
+            Action::Start => 'S',
+            Action::Forward => 'F',
+            Action::TurnLeft => 'L',
+            Action::TurnRight => 'R',
+        }
+    }
+}
+
+/// State of the reindeer along a path, on `position` facing `direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub position: Coord,
+    pub direction: Coord,
+    pub action: Action,
+}
+
+/// Costs of the moves of the reindeer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostModel {
+    /// Cost of a step forward, times the digit of the tile stepped on for
+    /// the tiles `1` to `9` of the maze.
+    pub forward: u32,
+    /// Cost of a quarter turn.
+    pub turn: u32,
+    /// Whether the reindeer can turn around, with two quarter turns.
+    pub u_turn: bool,
+}
+
+impl Default for CostModel {
+    /// Costs of the puzzle.
+    fn default() -> Self {
+        CostModel {
+            forward: 1,
+            turn: 1000,
+            u_turn: false,
+        }
+    }
+}
+
+pub struct Maze {
+    grid: Grid<u8>,
+    start: Coord,
+    end: Coord,
+    direction: Coord,
+    costs: CostModel,
+    /// Index offset of a step in each of [`Coord::DIRECTIONS`].
+    offsets: [isize; 4],
+}
+
+impl Maze {
+    fn new(input: &str, costs: CostModel) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| b"#.SE123456789".contains(&c).then_some(c))?;
+        if let Some(c) = grid.border().find(|&c: &Coord| grid[c] != b'#') {
+            let [x, y] = c.0.map(|x| x as usize);
+            let line = input.trim().lines().nth(y).unwrap_or_default();
+            return Err(ParseError::at(
+                input,
+                &line[x..],
+                "maze not closed by walls",
+            ));
+        }
+        let find = |tile: u8| {
+            grid.position(|&x| x == tile)
+                .ok_or_else(|| ParseError::eof(input, format!("missing tile `{}`", tile as char)))
+        };
+        let start = find(b'S')?;
+        let end = find(b'E')?;
+        // a best path goes through each state at most once, the costs of
+        // all its moves must add up within 32 bits
+        let max_move = 2 * costs.turn as u64 + 9 * costs.forward as u64;
+        if 4 * grid.len() as u64 * max_move > u32::MAX as u64 {
+            return Err(ParseError::unlocated(format!(
+                "costs forward={} and turn={} too high for a {}x{} maze",
+                costs.forward,
+                costs.turn,
+                grid.nx(),
+                grid.ny()
+            )));
+        }
+        let direction = Coord::RIGHT;
+        let nx = grid.nx() as isize;
+        let offsets = Coord::DIRECTIONS.map(|d| d.x() as isize + d.y() as isize * nx);
+        Ok(Maze {
+            grid,
+            start,
+            end,
+            direction,
+            costs,
+            offsets,
+        })
+    }
+    #[inline(always)]
+    fn idx(&self, coord: &Coord) -> usize {
+        let idx = self.grid.idx(*coord);
+        debug_assert!(idx.is_some());
+        idx.unwrap_or_default()
+    }
+    /// Paths from the start to the end with the lowest score, with the
+    /// costs of the maze.
+    pub fn best_paths(&self) -> BestPaths<'_> {
+        BestPaths {
+            maze: self,
+            search: self.search(&self.costs),
+        }
+    }
+    /// State of the reindeer on `position` facing `direction`, as
+    /// `4 * cell + direction` with directions indexed as in
+    /// [`Coord::DIRECTIONS`].
+    fn state(&self, position: Coord, direction: Coord) -> usize {
+        let dir = Coord::DIRECTIONS.iter().position(|&d| d == direction);
+        4 * self.idx(&position) + dir.unwrap_or_default()
+    }
+    /// Steps between `states`, each move of the search being split into
+    /// turns and a step forward.
+    fn steps(&self, mut states: impl Iterator<Item = usize>) -> Vec<Step> {
+        let step = |state: usize, action| Step {
+            position: self.grid.pos(state / 4),
+            direction: Coord::DIRECTIONS[state % 4],
+            action,
+        };
+        let Some(first) = states.next() else {
+            return Vec::new();
+        };
+        let mut steps = vec![step(first, Action::Start)];
+        for state in states {
+            let Step {
+                position,
+                mut direction,
+                ..
+            } = steps[steps.len() - 1];
+            let next_direction = Coord::DIRECTIONS[state % 4];
+            let turn = if next_direction == direction.rotate_left() {
+                Action::TurnLeft
+            } else {
+                Action::TurnRight
+            };
+            while direction != next_direction {
+                direction = match turn {
+                    Action::TurnLeft => direction.rotate_left(),
+                    _ => direction.rotate_right(),
+                };
+                steps.push(Step {
+                    position,
+                    direction,
+                    action: turn,
+                });
+            }
+            steps.push(step(state, Action::Forward));
+        }
+        steps
+    }
+    /// Cost of a step forward onto the cell `idx`.
+    fn forward_cost(&self, costs: &CostModel, idx: usize) -> u32 {
+        match self.grid[idx] {
+            digit @ b'1'..=b'9' => costs.forward * (digit - b'0') as u32,
+            _ => costs.forward,
+        }
+    }
+    /// Moves from `state` with their cost: straight, quarter turns and
+    /// U-turn if allowed, each followed by a step forward.
+    #[inline(always)]
+    fn moves<'a>(
+        &'a self,
+        costs: &'a CostModel,
+        state: usize,
+    ) -> impl Iterator<Item = (usize, u32)> + 'a {
+        let (cell, dir) = (state / 4, state % 4);
+        let n_moves = if costs.u_turn { 4 } else { 3 };
+        // directions with the number of quarter turns to face them
+        let nexts = [
+            (0, dir),
+            (1, (dir + 1) % 4),
+            (1, (dir + 3) % 4),
+            (2, (dir + 2) % 4),
+        ];
+        nexts
+            .into_iter()
+            .take(n_moves)
+            .filter_map(move |(turns, next_dir)| {
+                let nei = (cell as isize + self.offsets[next_dir]) as usize;
+                let cost = turns * costs.turn + self.forward_cost(costs, nei);
+                (self.grid[nei] != b'#').then_some((4 * nei + next_dir, cost))
+            })
+    }
+    fn search(&self, costs: &CostModel) -> Search<'_> {
+        let end = self.idx(&self.end);
+        let start = self.state(self.start, self.direction);
+        let neighbors = |state| self.moves(costs, state);
+        let n_states = 4 * self.grid.len();
+        aoc_search::dijkstra_fixed(n_states, self, [start], neighbors, |state| state / 4 == end)
+    }
+}
+
+/// Every path of a [`Maze`] from the start to the end with the lowest score,
+/// from [`Maze::best_paths`].
+pub struct BestPaths<'a> {
+    maze: &'a Maze,
+    search: Search<'a>,
+}
+
+impl BestPaths<'_> {
+    /// Lowest score, if the end can be reached.
+    pub fn cost(&self) -> Option<u32> {
+        self.search.best()
+    }
+    /// Every best path, found one after the other, each as the steps of the
+    /// reindeer from the start to the end.
+    pub fn iter(&self) -> impl Iterator<Item = Vec<Step>> + '_ {
+        let search = &self.search;
+        search
+            .ends()
+            .iter()
+            .flat_map(|&end| search.paths(end))
+            .map(|states| self.maze.steps(states.into_iter()))
+    }
+    /// Whether each cell, by index, is on a best path.
+    fn tiles(&self) -> Vec<bool> {
+        let search = &self.search;
+        let states = search.on_best_paths(search.ends().iter().copied());
+        states
+            .chunks(4)
+            .map(|directions| directions.contains(&true))
+            .collect()
+    }
+    /// The maze with the tiles on the best paths marked with `O`.
+    pub fn render(&self) -> String {
+        let mut grid = self.maze.grid.clone();
+        for (idx, tile) in self.tiles().into_iter().enumerate() {
+            if tile {
+                grid[idx] = b'O';
+            }
+        }
+        grid.to_string()
+    }
+}
+
+/// A state is reached from the cell behind it, facing any direction.
+impl FixedDegree<usize> for &Maze {
+    fn slot(&self, from: usize, _: usize) -> u32 {
+        (from % 4) as u32
+    }
+    fn pred(&self, to: usize, slot: u32) -> usize {
+        let behind = (to / 4) as isize - self.offsets[to % 4];
+        4 * behind as usize + slot as usize
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE1: &str = "
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+#[cfg(test)]
+const EXAMPLE2: &str = "
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+#[test]
+fn test1() {
+    assert_eq!(
+        part1(&Maze::new(EXAMPLE1, CostModel::default()).unwrap()),
+        Some(7036)
+    );
+    assert_eq!(
+        part1(&Maze::new(EXAMPLE2, CostModel::default()).unwrap()),
+        Some(11048)
+    );
+}
+#[test]
+fn test2() {
+    assert_eq!(
+        part2(&Maze::new(EXAMPLE1, CostModel::default()).unwrap()),
+        Some(45)
+    );
+    assert_eq!(
+        part2(&Maze::new(EXAMPLE2, CostModel::default()).unwrap()),
+        Some(64)
+    );
+}
+#[test]
+fn test_best_paths() {
+    let maze = Maze::new(EXAMPLE1, CostModel::default()).unwrap();
+    let best_paths = maze.best_paths();
+    assert_eq!(best_paths.cost(), Some(7036));
+    let paths = best_paths.iter().collect::<Vec<_>>();
+    assert_eq!(paths.len(), 3);
+    assert_eq!(best_paths.iter().take(2).count(), 2);
+    for path in &paths {
+        let cost = path.iter().map(|step| match step.action {
+            Action::Start => 0,
+            Action::Forward => 1,
+            Action::TurnLeft | Action::TurnRight => 1000,
+        });
+        assert_eq!(cost.sum::<u32>(), 7036);
+        assert_eq!(path[0].position, maze.start);
+        assert_eq!(path[path.len() - 1].position, maze.end);
+    }
+    let rendered = best_paths.render();
+    assert_eq!(rendered.bytes().filter(|&c| c == b'O').count(), 45);
+    assert!(rendered.starts_with("###############\n#.......#....O#\n"));
+}
+#[test]
+fn test_cost_model() {
+    let costs = |forward, turn, u_turn| CostModel {
+        forward,
+        turn,
+        u_turn,
+    };
+    let maze = "#####\n#..E#\n#.#.#\n#S..#\n#####\n";
+    assert_eq!(
+        part1(&Maze::new(maze, costs(1, 0, false)).unwrap()),
+        Some(4)
+    );
+    assert_eq!(
+        part1(&Maze::new(maze, costs(10, 1, false)).unwrap()),
+        Some(41)
+    );
+    let weighted = "#####\n#..E#\n#.#.#\n#S9.#\n#####\n";
+    assert_eq!(
+        part1(&Maze::new(weighted, CostModel::default()).unwrap()),
+        Some(1012)
+    );
+    assert_eq!(
+        part1(&Maze::new(weighted, costs(1, 1, false)).unwrap()),
+        Some(6)
+    );
+    let behind = "#####\n#E.S#\n#####\n";
+    let maze = Maze::new(behind, CostModel::default()).unwrap();
+    assert_eq!(Day16::part1(&maze), Err(no_path()));
+    assert_eq!(Day16::part2(&maze), Err(no_path()));
+    assert!(explain(&maze).ends_with("#E.S#\n#####\nno path from `S` to `E`\n"));
+    assert_eq!(
+        Maze::new(behind, costs(4_000_000_000, 1000, false)).err(),
+        Some(ParseError::unlocated(
+            "costs forward=4000000000 and turn=1000 too high for a 5x3 maze"
+        ))
+    );
+    let maze = Maze::new(behind, costs(1, 1000, true)).unwrap();
+    assert_eq!(part1(&maze), Some(2002));
+    let path = maze.best_paths().iter().next().unwrap();
+    let actions = path.iter().map(|step| step.action.symbol());
+    assert_eq!(actions.collect::<String>(), "SRRFF");
+}
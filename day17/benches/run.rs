@@ -0,0 +1,33 @@
+//! Part 2 on the puzzle input, and the search of register A it runs with
+//! each interpreter: `run_program` decoding each instruction of the tried
+//! iterations, or `Program` decoded once. `cargo bench -p day17`.
+
+use std::{fs, hint::black_box, path::Path};
+
+use aoc_core::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day17::{lowest_a_with, Day17, Interpreter};
+
+fn part2(c: &mut Criterion) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs/day17.txt");
+    let input =
+        fs::read_to_string(&path).unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()));
+    let parsed = Day17::parse(&input).unwrap();
+    c.bench_function("day17/part2", |b| {
+        b.iter(|| Day17::part2(black_box(&parsed)).unwrap())
+    });
+    let binary = &parsed.1;
+    let mut group = c.benchmark_group("day17/search");
+    for (name, interpreter) in [
+        ("run_program", Interpreter::Reference),
+        ("Program", Interpreter::Decoded),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| lowest_a_with(black_box(binary), binary, interpreter).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, part2);
+criterion_main!(benches);
@@ -0,0 +1,1098 @@
+use std::collections::HashSet;
+
+use aoc_core::{parse_token, split_token, strip_token, ParseError, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    /// Initial registers and the program.
+    type Input<'a> = ([u64; 3], Vec<u8>);
+    type Answer1 = String;
+    type Answer2 = u64;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+    fn part1((registers, binary): &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        part1(*registers, binary)
+    }
+    fn part2((registers, binary): &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        part2(*registers, binary)
+    }
+    fn explain((registers, binary): &Self::Input<'_>) -> Option<String> {
+        // both only fail on a program that `parse` rejects
+        Some(format!(
+            "{}\n{}",
+            disassemble(binary).ok()?,
+            trace(*registers, binary).ok()?
+        ))
+    }
+}
+
+fn part1(registers: [u64; 3], binary: &[u8]) -> Result<String, ParseError> {
+    let mut output = Vec::with_capacity(binary.len());
+    Program::new(binary)?.run(registers, &mut output, false)?;
+    Ok(output
+        .into_iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(","))
+}
+
+/// The program outputs itself for the lowest value of register A found by
+/// [`lowest_a`].
+fn part2(registers: [u64; 3], binary: &[u8]) -> Result<u64, ParseError> {
+    let a = lowest_a(binary, binary)?
+        .ok_or_else(|| ParseError::unlocated("no value of register A outputs the program"))?;
+    debug_assert!({
+        let mut output = Vec::new();
+        run_program(binary, [a, registers[B], registers[C]], &mut output, true) == Ok(None)
+    });
+    Ok(a)
+}
+
+/// Program decompiled as a loop over the digits of register A, such as the
+/// example:
+/// ```python
+/// while A != 0:
+///     A //= 8
+///     print(A % 8)
+/// ```
+/// Each iteration shifts `A` right by `shift` bits once, outputs one value,
+/// and computes `B` and `C` from `A` alone.
+struct Loop {
+    /// Instructions of an iteration, without the final `jnz 0`.
+    body: Program,
+    shift: u32,
+}
+
+impl Loop {
+    fn decompile(binary: &[u8]) -> Result<Self, String> {
+        let Some((body, [JNZ, 0])) = binary.split_last_chunk::<2>() else {
+            return Err("it does not end with `jnz 0`".to_string());
+        };
+        let count = |instruction| body.chunks(2).filter(|i| i[0] == instruction).count();
+        if count(JNZ) > 0 {
+            return Err("it jumps before its end".to_string());
+        }
+        if count(OUT) != 1 {
+            return Err("it does not output one value per iteration".to_string());
+        }
+        let shifts = body.chunks(2).filter(|i| i[0] == ADV).collect::<Vec<_>>();
+        let shift = match shifts[..] {
+            [[ADV, shift @ 1..=3]] => *shift as u32,
+            _ => return Err("it does not shift A by a constant once".to_string()),
+        };
+        // registers written in the iteration so far
+        let mut written = [true, false, false];
+        for instr in body.chunks(2) {
+            let (instruction, operand) = (instr[0], instr[1]);
+            let mut reads = Vec::new();
+            if matches!(instruction, ADV | BST | OUT | BDV | CDV) {
+                match operand {
+                    0..=3 => {}
+                    4..=6 => reads.push(operand as usize - 4),
+                    _ => return Err("it uses the reserved combo operand 7".to_string()),
+                }
+            }
+            if matches!(instruction, ADV | BDV | CDV) {
+                reads.push(A);
+            }
+            if matches!(instruction, BXL | BXC) {
+                reads.push(B);
+            }
+            if instruction == BXC {
+                reads.push(C);
+            }
+            if let Some(&r) = reads.iter().find(|&&r| !written[r]) {
+                let name = combo_name(r as u8 + 4);
+                return Err(format!("it reads {name} before writing it"));
+            }
+            match instruction {
+                BXL | BST | BXC | BDV => written[B] = true,
+                CDV => written[C] = true,
+                _ => {}
+            }
+        }
+        Ok(Loop {
+            body: Program::new(body).map_err(|e| e.msg)?,
+            shift,
+        })
+    }
+    /// Value output by the iteration starting with `a` in register A, run by
+    /// `interpreter` (with `buffer` for the output of [`run_program`]).
+    fn output(
+        &self,
+        a: u64,
+        interpreter: Interpreter,
+        buffer: &mut Vec<u8>,
+    ) -> Result<u8, ParseError> {
+        let first = match interpreter {
+            Interpreter::Decoded => self.body.first_output([a, 0, 0])?,
+            Interpreter::Reference => {
+                run_checked(&self.body.binary, [a, 0, 0], buffer, false)?;
+                buffer.first().copied()
+            }
+        };
+        Ok(first.unwrap_or_default())
+    }
+}
+
+/// Interpreter running the iterations tried by [`lowest_a_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpreter {
+    /// [`Program`], decoded once.
+    Decoded,
+    /// [`run_program`], decoding each instruction when it runs.
+    Reference,
+}
+
+/// [`lowest_a_with`] the [`Program`] interpreter.
+fn lowest_a(binary: &[u8], target: &[u8]) -> Result<Option<u64>, ParseError> {
+    lowest_a_with(binary, target, Interpreter::Decoded)
+}
+
+/// Lowest value of register A for which `binary` outputs `target`, if any,
+/// or why the program is not a loop that this search can reverse, or why A
+/// cannot hold the values of every iteration. Each iteration tried is run by
+/// `interpreter`.
+///
+/// The iteration `i` starts with the bits of A above `i * shift`, so the
+/// digits of A are chosen from the last iteration back to the first one,
+/// the lowest first: the first complete value found is the lowest one.
+pub fn lowest_a_with(
+    binary: &[u8],
+    target: &[u8],
+    interpreter: Interpreter,
+) -> Result<Option<u64>, ParseError> {
+    check_program(binary)?;
+    let program = Loop::decompile(binary).map_err(|reason| {
+        ParseError::unlocated(format!(
+            "the program is not a loop over the digits of register A: {reason}"
+        ))
+    })?;
+    let digits = 1u64 << program.shift;
+    if target.is_empty() {
+        return Ok(None);
+    }
+    let bits = program.shift as usize * target.len();
+    if bits > 64 {
+        return Err(ParseError::unlocated(format!(
+            "the program needs {bits} bits of register A to output {} values, more than 64",
+            target.len()
+        )));
+    }
+    // values of A at the start of the iterations from the last one, with
+    // the next digit to try
+    let mut stack = vec![(0u64, 0u64)];
+    let mut buffer = Vec::new();
+    while let Some((a, digit)) = stack.pop() {
+        if digit == digits {
+            continue;
+        }
+        stack.push((a, digit + 1));
+        let i = target.len() - stack.len();
+        let next = (a << program.shift) | digit;
+        // the loop goes on while A is not 0 after the shift, so only the
+        // single iteration of a single output can start with A = 0
+        if (next == 0 && target.len() > 1)
+            || program.output(next, interpreter, &mut buffer)? != target[i]
+        {
+            continue;
+        }
+        if i == 0 {
+            return Ok(Some(next));
+        }
+        stack.push((next, 0));
+    }
+    Ok(None)
+}
+
+fn parse(input: &str) -> Result<([u64; 3], Vec<u8>), ParseError> {
+    let (header, program) = split_token(input, input.trim(), "\n\n")?;
+    let mut lines = header.lines();
+    let mut registers: [u64; 3] = [0; 3];
+    for (register, name) in registers.iter_mut().zip(['A', 'B', 'C']) {
+        let prefix = format!("Register {name}: ");
+        let line = lines.next().ok_or_else(|| {
+            ParseError::at(
+                input,
+                &header[header.len()..],
+                format!("expected `{prefix}`"),
+            )
+        })?;
+        *register = parse_token(input, strip_token(input, line, &prefix)?)?;
+    }
+    let tokens = strip_token(input, program, "Program: ")?
+        .split(',')
+        .collect::<Vec<_>>();
+    let binary = tokens
+        .iter()
+        .map(|x| {
+            let value = parse_token(input, x)?;
+            if value >= 8 {
+                return Err(ParseError::at(input, x, "expected a 3-bit value"));
+            }
+            Ok(value)
+        })
+        .collect::<Result<Vec<u8>, ParseError>>()?;
+    if !binary.len().is_multiple_of(2) {
+        return Err(ParseError::eof(
+            input,
+            "program ends with an opcode without operand",
+        ));
+    }
+    if let Some(address) = reserved_operand(&binary) {
+        return Err(ParseError::at(
+            input,
+            tokens[address + 1],
+            format!(
+                "reserved combo operand 7 of `{}`",
+                MNEMONICS[binary[address] as usize]
+            ),
+        ));
+    }
+    Ok((registers, binary))
+}
+/// Address of an instruction that can run with the reserved combo operand 7,
+/// if any. Instructions at odd addresses only run after a `jnz` there, and
+/// then go on at odd addresses.
+fn reserved_operand(binary: &[u8]) -> Option<usize> {
+    let jumps_to = |parity: usize| {
+        (parity..binary.len().saturating_sub(1))
+            .step_by(2)
+            .filter(|&i| binary[i] == JNZ && binary[i + 1] % 2 == 1)
+            .map(|i| binary[i + 1] as usize)
+            .min()
+    };
+    // the odd addresses run from the lowest odd target of a `jnz` that runs
+    let mut odd_from = jumps_to(0);
+    while let Some(from) = odd_from {
+        match jumps_to(from).filter(|&target| target < from) {
+            Some(target) => odd_from = Some(target),
+            None => break,
+        }
+    }
+    let even = (0..binary.len().saturating_sub(1)).step_by(2);
+    let odd = odd_from.map_or(0..0, |from| from..binary.len().saturating_sub(1));
+    even.chain(odd.step_by(2))
+        .find(|&i| matches!(binary[i], ADV | BST | OUT | BDV | CDV) && binary[i + 1] == 7)
+}
+
+/// Reference interpreter of the program, decoding each instruction when it
+/// runs: outputs into `output` and returns `None` when the output is the
+/// program itself, or the length of the output otherwise. With `shortcut`
+/// the run stops at the first value that does not match the program, a
+/// value past its end included, which is left out of `output`.
+///
+/// Fails on a program that [`parse`] would reject, or that jumps more than
+/// [`MAX_JUMPS`] times.
+pub fn run_program(
+    binary: &[u8],
+    registers: [u64; 3],
+    output: &mut Vec<u8>,
+    shortcut: bool,
+) -> Result<Option<usize>, ParseError> {
+    check_program(binary)?;
+    run_checked(binary, registers, output, shortcut)
+}
+/// [`run_program`] on a program already checked.
+fn run_checked(
+    binary: &[u8],
+    mut registers: [u64; 3],
+    output: &mut Vec<u8>,
+    shortcut: bool,
+) -> Result<Option<usize>, ParseError> {
+    output.clear();
+    let mut instr_ptr = 0;
+    let mut jumps = 0;
+    while instr_ptr + 1 < binary.len() {
+        match exec(binary[instr_ptr], binary[instr_ptr + 1], &mut registers) {
+            Effect::Next => {}
+            Effect::Jump(target) => {
+                jumps += 1;
+                if jumps > MAX_JUMPS {
+                    return Err(too_many_jumps());
+                }
+                instr_ptr = target;
+                continue;
+            }
+            Effect::Out(new_out) => {
+                if shortcut && (output.len() >= binary.len() || binary[output.len()] != new_out) {
+                    return Ok(Some(output.len()));
+                }
+                output.push(new_out);
+            }
+        }
+        instr_ptr += 2;
+    }
+    Ok(if output == binary {
+        None
+    } else {
+        Some(output.len())
+    })
+}
+
+/// Jumps a run takes at most, to stop the programs that never halt.
+pub const MAX_JUMPS: usize = 1_000_000;
+
+fn too_many_jumps() -> ParseError {
+    ParseError::unlocated(format!(
+        "the program jumps more than {MAX_JUMPS} times without halting"
+    ))
+}
+
+/// Program decoded once, each instruction being specialized for its combo
+/// operand, to run it many times without decoding each step again.
+pub struct Program {
+    binary: Vec<u8>,
+    /// Operation at each address, those at odd addresses only running after
+    /// a jump there.
+    ops: Vec<Op>,
+}
+
+/// Instruction with its operand resolved: `Lit` for a literal combo
+/// operand, `A`, `B` or `C` for a register.
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    AdvLit(u64),
+    AdvA,
+    AdvB,
+    AdvC,
+    BdvLit(u64),
+    BdvA,
+    BdvB,
+    BdvC,
+    CdvLit(u64),
+    CdvA,
+    CdvB,
+    CdvC,
+    Bxl(u64),
+    BstLit(u64),
+    BstA,
+    BstB,
+    BstC,
+    Jnz(usize),
+    Bxc,
+    OutLit(u8),
+    OutA,
+    OutB,
+    OutC,
+    /// Instruction with the reserved combo operand 7.
+    Reserved,
+}
+
+impl Program {
+    /// Program of `binary`, or why [`parse`] would reject it.
+    pub fn new(binary: &[u8]) -> Result<Self, ParseError> {
+        check_program(binary)?;
+        let decode = |instr: &[u8]| {
+            use Op::*;
+            let lit = instr[1] as u64;
+            // variants for the literals and the registers `a`, `b`, `c`
+            let combo = |ops: [Op; 4]| match instr[1] {
+                0..=3 => ops[0],
+                4..=6 => ops[instr[1] as usize - 3],
+                _ => Reserved,
+            };
+            match instr[0] {
+                ADV => combo([AdvLit(lit), AdvA, AdvB, AdvC]),
+                BXL => Bxl(lit),
+                BST => combo([BstLit(lit), BstA, BstB, BstC]),
+                JNZ => Jnz(lit as usize),
+                BXC => Bxc,
+                OUT => combo([OutLit(lit as u8), OutA, OutB, OutC]),
+                BDV => combo([BdvLit(lit), BdvA, BdvB, BdvC]),
+                _ => combo([CdvLit(lit), CdvA, CdvB, CdvC]),
+            }
+        };
+        Ok(Program {
+            binary: binary.to_vec(),
+            ops: binary.windows(2).map(decode).collect(),
+        })
+    }
+    /// Same as [`run_program`], with the same result.
+    pub fn run(
+        &self,
+        registers: [u64; 3],
+        output: &mut Vec<u8>,
+        shortcut: bool,
+    ) -> Result<Option<usize>, ParseError> {
+        output.clear();
+        let binary = &self.binary;
+        let mut mismatch = false;
+        let mut out = |value: u8| {
+            if shortcut && binary.get(output.len()) != Some(&value) {
+                mismatch = true;
+                return false;
+            }
+            output.push(value);
+            true
+        };
+        self.exec(registers, &mut out)?;
+        Ok(if !mismatch && output == binary {
+            None
+        } else {
+            Some(output.len())
+        })
+    }
+    /// First value output running from `registers`, if any.
+    fn first_output(&self, registers: [u64; 3]) -> Result<Option<u8>, ParseError> {
+        let mut first = None;
+        self.exec(registers, &mut |value| {
+            first = Some(value);
+            false
+        })?;
+        Ok(first)
+    }
+    /// Run from `registers` until the program halts or `out` returns false
+    /// on a value output, or fail after [`MAX_JUMPS`] jumps.
+    #[inline(always)]
+    fn exec(
+        &self,
+        [mut a, mut b, mut c]: [u64; 3],
+        out: &mut impl FnMut(u8) -> bool,
+    ) -> Result<(), ParseError> {
+        let mut instr_ptr = 0;
+        let mut jumps = 0;
+        while let Some(&op) = self.ops.get(instr_ptr) {
+            instr_ptr += 2;
+            let value = match op {
+                Op::AdvLit(x) => {
+                    a >>= x;
+                    continue;
+                }
+                Op::AdvA => {
+                    a = shr(a, a);
+                    continue;
+                }
+                Op::AdvB => {
+                    a = shr(a, b);
+                    continue;
+                }
+                Op::AdvC => {
+                    a = shr(a, c);
+                    continue;
+                }
+                Op::BdvLit(x) => {
+                    b = a >> x;
+                    continue;
+                }
+                Op::BdvA => {
+                    b = shr(a, a);
+                    continue;
+                }
+                Op::BdvB => {
+                    b = shr(a, b);
+                    continue;
+                }
+                Op::BdvC => {
+                    b = shr(a, c);
+                    continue;
+                }
+                Op::CdvLit(x) => {
+                    c = a >> x;
+                    continue;
+                }
+                Op::CdvA => {
+                    c = shr(a, a);
+                    continue;
+                }
+                Op::CdvB => {
+                    c = shr(a, b);
+                    continue;
+                }
+                Op::CdvC => {
+                    c = shr(a, c);
+                    continue;
+                }
+                Op::Bxl(x) => {
+                    b ^= x;
+                    continue;
+                }
+                Op::BstLit(x) => {
+                    b = x;
+                    continue;
+                }
+                Op::BstA => {
+                    b = a % 8;
+                    continue;
+                }
+                Op::BstB => {
+                    b %= 8;
+                    continue;
+                }
+                Op::BstC => {
+                    b = c % 8;
+                    continue;
+                }
+                Op::Jnz(target) => {
+                    if a != 0 {
+                        jumps += 1;
+                        if jumps > MAX_JUMPS {
+                            return Err(too_many_jumps());
+                        }
+                        instr_ptr = target;
+                    }
+                    continue;
+                }
+                Op::Bxc => {
+                    b ^= c;
+                    continue;
+                }
+                Op::OutLit(x) => x,
+                Op::OutA => (a % 8) as u8,
+                Op::OutB => (b % 8) as u8,
+                Op::OutC => (c % 8) as u8,
+                Op::Reserved => {
+                    return Err(ParseError::unlocated(format!(
+                        "reserved combo operand 7 at address {}",
+                        instr_ptr - 2
+                    )));
+                }
+            };
+            if !out(value) {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `x` divided by `2^shift`, which may be over 64 bits.
+#[inline(always)]
+fn shr(x: u64, shift: u64) -> u64 {
+    let shift = shift.try_into().unwrap_or(u32::MAX);
+    x.checked_shr(shift).unwrap_or(0)
+}
+
+/// What an instruction does besides updating the registers.
+enum Effect {
+    Next,
+    Jump(usize),
+    Out(u8),
+}
+
+#[inline(always)]
+fn exec(instruction: u8, operand: u8, registers: &mut [u64; 3]) -> Effect {
+    match instruction {
+        ADV => {
+            registers[A] = shr(registers[A], combo(operand, registers));
+        }
+        BXL => {
+            registers[B] ^= operand as u64;
+        }
+        BST => {
+            registers[B] = combo(operand, registers) % 8;
+        }
+        JNZ => {
+            if registers[A] != 0 {
+                return Effect::Jump(operand as usize);
+            }
+        }
+        BXC => {
+            registers[B] ^= registers[C];
+        }
+        OUT => {
+            return Effect::Out((combo(operand, registers) % 8) as u8);
+        }
+        BDV => {
+            registers[B] = shr(registers[A], combo(operand, registers));
+        }
+        _ => {
+            registers[C] = shr(registers[A], combo(operand, registers));
+        }
+    }
+    Effect::Next
+}
+
+fn combo(operand: u8, registers: &[u64; 3]) -> u64 {
+    match operand {
+        0..=3 => operand as u64,
+        4..7 => registers[(operand - 4) as usize],
+        _ => unreachable!("reserved combo operand 7, rejected by `parse`"),
+    }
+}
+
+/// Listing of the program, one `mnemonic operand` per line. Combo operands
+/// are `0` to `3`, the registers `a`, `b`, `c` and the reserved `7`; the
+/// targets of `jnz` get a label `l<address>` when they are instructions.
+/// The operand of `bxc`, which the machine ignores, is kept as a literal.
+pub fn disassemble(binary: &[u8]) -> Result<String, ParseError> {
+    check_binary(binary)?;
+    let label = |target: usize| target.is_multiple_of(2) && target < binary.len();
+    let targets = binary
+        .chunks(2)
+        .filter(|instr| instr[0] == JNZ && label(instr[1] as usize))
+        .map(|instr| instr[1] as usize)
+        .collect::<HashSet<_>>();
+    let mut out = String::new();
+    for (i, instr) in binary.chunks(2).enumerate() {
+        let prefix = if targets.contains(&(2 * i)) {
+            format!("l{}:", 2 * i)
+        } else {
+            String::new()
+        };
+        let (instruction, operand) = (instr[0], instr[1]);
+        let operand = match instruction {
+            JNZ if label(operand as usize) => format!("l{operand}"),
+            ADV | BST | OUT | BDV | CDV => combo_name(operand).to_string(),
+            _ => operand.to_string(),
+        };
+        out += &format!("{prefix:<4}{} {operand}\n", MNEMONICS[instruction as usize]);
+    }
+    Ok(out)
+}
+fn combo_name(operand: u8) -> &'static str {
+    ["0", "1", "2", "3", "a", "b", "c", "7"][operand as usize]
+}
+
+/// Program of the mnemonic `source`, one instruction per line such as the
+/// listing of [`disassemble`]. A line may start with a `label:` that `jnz`
+/// can jump to, and the operand of `bxc` may be left out.
+pub fn assemble(source: &str) -> Result<Vec<u8>, ParseError> {
+    // labels and instructions of each line
+    let mut lines = Vec::new();
+    let mut labels = Vec::new();
+    for line in source.lines() {
+        let mut tokens = line.split_whitespace().peekable();
+        let label = tokens.next_if(|token| token.ends_with(':'));
+        if let Some(label) = label {
+            let name = &label[..label.len() - 1];
+            if labels.iter().any(|&(l, _)| l == name) {
+                return Err(ParseError::at(
+                    source,
+                    label,
+                    format!("duplicate label `{name}`"),
+                ));
+            }
+            labels.push((name, 2 * lines.len()));
+        }
+        let tokens = tokens.collect::<Vec<_>>();
+        if !tokens.is_empty() {
+            lines.push(tokens);
+        }
+    }
+    let mut binary = Vec::with_capacity(2 * lines.len());
+    for tokens in lines {
+        let (mnemonic, operand) = match tokens[..] {
+            [mnemonic] => (mnemonic, None),
+            [mnemonic, operand] => (mnemonic, Some(operand)),
+            [_, _, extra, ..] => return Err(ParseError::at(source, extra, "expected one operand")),
+            [] => continue,
+        };
+        let Some(instruction) = MNEMONICS.iter().position(|&m| m == mnemonic) else {
+            let msg = format!("unknown instruction `{mnemonic}`");
+            return Err(ParseError::at(source, mnemonic, msg));
+        };
+        let instruction = instruction as u8;
+        let missing = || ParseError::at(source, &mnemonic[mnemonic.len()..], "missing operand");
+        let literal = |operand: &str| {
+            operand
+                .parse::<u8>()
+                .ok()
+                .filter(|&value| value < 8)
+                .ok_or_else(|| {
+                    ParseError::at(source, operand, "expected a literal operand `0`-`7`")
+                })
+        };
+        let operand = match (instruction, operand) {
+            (BXC, None) => 0,
+            (_, None) => return Err(missing()),
+            (BXL | BXC, Some(operand)) => literal(operand)?,
+            (JNZ, Some(operand)) => match labels.iter().find(|&&(l, _)| l == operand) {
+                Some(&(_, address)) if address < 8 => address as u8,
+                Some(&(_, address)) => {
+                    let msg = format!("label `{operand}` at address {address} out of reach");
+                    return Err(ParseError::at(source, operand, msg));
+                }
+                None => literal(operand)?,
+            },
+            (_, Some("7")) => {
+                return Err(ParseError::at(
+                    source,
+                    operand.unwrap_or_default(),
+                    "reserved combo operand 7",
+                ));
+            }
+            (_, Some(operand)) => {
+                let combo = (0..7).find(|&c| combo_name(c) == operand);
+                combo.ok_or_else(|| {
+                    let msg = "expected a combo operand `0`-`3`, `a`, `b` or `c`";
+                    ParseError::at(source, operand, msg)
+                })?
+            }
+        };
+        binary.extend([instruction, operand]);
+    }
+    Ok(binary)
+}
+
+/// Steps run from `registers`, each with the registers after it and what it
+/// outputs, cut after [`TRACE_STEPS`] steps.
+pub fn trace(mut registers: [u64; 3], binary: &[u8]) -> Result<String, ParseError> {
+    check_binary(binary)?;
+    let mut out = format!(
+        "      a={} b={} c={}\n",
+        registers[A], registers[B], registers[C]
+    );
+    let mut instr_ptr = 0;
+    for _ in 0..TRACE_STEPS {
+        if instr_ptr + 1 >= binary.len() {
+            out += "halt\n";
+            return Ok(out);
+        }
+        let (instruction, operand) = (binary[instr_ptr], binary[instr_ptr + 1]);
+        if instruction != JNZ && instruction != BXL && instruction != BXC && operand == 7 {
+            out += &format!("{instr_ptr:>3}  reserved combo operand 7\n");
+            return Ok(out);
+        }
+        let effect = exec(instruction, operand, &mut registers);
+        let name = match instruction {
+            JNZ | BXL | BXC => operand.to_string(),
+            _ => combo_name(operand).to_string(),
+        };
+        out += &format!(
+            "{instr_ptr:>3}  {} {name:<2} a={} b={} c={}",
+            MNEMONICS[instruction as usize], registers[A], registers[B], registers[C]
+        );
+        match effect {
+            Effect::Next => instr_ptr += 2,
+            Effect::Jump(target) => instr_ptr = target,
+            Effect::Out(value) => {
+                out += &format!(" out={value}");
+                instr_ptr += 2;
+            }
+        }
+        out.push('\n');
+    }
+    out += "...\n";
+    Ok(out)
+}
+const TRACE_STEPS: usize = 1000;
+
+/// Why [`parse`] would reject `binary`, if it would.
+fn check_program(binary: &[u8]) -> Result<(), ParseError> {
+    check_binary(binary)?;
+    match reserved_operand(binary) {
+        Some(address) => Err(ParseError::unlocated(format!(
+            "reserved combo operand 7 of `{}` at address {address}",
+            MNEMONICS[binary[address] as usize]
+        ))),
+        None => Ok(()),
+    }
+}
+/// Why `binary` is not a program of 3-bit opcodes each followed by a 3-bit
+/// operand, if it is not.
+fn check_binary(binary: &[u8]) -> Result<(), ParseError> {
+    if let Some(address) = binary.iter().position(|&x| x >= 8) {
+        return Err(ParseError::unlocated(format!(
+            "value {} at address {address} is not 3 bits",
+            binary[address]
+        )));
+    }
+    if !binary.len().is_multiple_of(2) {
+        return Err(ParseError::unlocated(
+            "program ends with an opcode without operand",
+        ));
+    }
+    Ok(())
+}
+
+// registers
+const A: usize = 0;
+const B: usize = 1;
+const C: usize = 2;
+// instructions
+const ADV: u8 = 0;
+const BXL: u8 = 1;
+const BST: u8 = 2;
+const JNZ: u8 = 3;
+const BXC: u8 = 4;
+const OUT: u8 = 5;
+const BDV: u8 = 6;
+const CDV: u8 = 7;
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+#[test]
+fn test1() {
+    const EXAMPLE: &str = "
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+    let (registers, binary) = parse(EXAMPLE).unwrap();
+    assert_eq!(
+        part1(registers, &binary).as_deref(),
+        Ok("4,6,3,5,6,3,5,2,1,0")
+    );
+    // `jnz 0` with A = 1 forever
+    assert_eq!(
+        part1([1, 0, 0], &[3, 0]),
+        Err(ParseError::unlocated(
+            "the program jumps more than 1000000 times without halting"
+        ))
+    );
+}
+#[test]
+fn test2() {
+    const EXAMPLE: &str = "
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+    let (registers, binary) = parse(EXAMPLE).unwrap();
+    assert_eq!(part2(registers, &binary), Ok(117440));
+}
+#[test]
+fn test_disassemble() {
+    let binary = [2, 4, 1, 7, 7, 5, 0, 3, 4, 4, 5, 5, 3, 0];
+    assert_eq!(
+        disassemble(&binary).as_deref(),
+        Ok("l0: bst a\n    bxl 7\n    cdv b\n    adv 3\n    bxc 4\n    out b\n    jnz l0\n")
+    );
+    assert_eq!(
+        disassemble(&[3, 3, 0, 7]).as_deref(),
+        Ok("    jnz 3\n    adv 7\n")
+    );
+    assert_eq!(
+        disassemble(&[3, 3, 0]),
+        Err(ParseError::unlocated(
+            "program ends with an opcode without operand"
+        ))
+    );
+    assert_eq!(
+        disassemble(&[0, 1, 8, 0]),
+        Err(ParseError::unlocated("value 8 at address 2 is not 3 bits"))
+    );
+    assert!(trace([1, 0, 0], &[0, 9]).is_err());
+    assert_eq!(
+        trace([10, 0, 0], &[0, 1, 5, 4, 3, 0]).unwrap(),
+        "      a=10 b=0 c=0
+  0  adv 1  a=5 b=0 c=0
+  2  out a  a=5 b=0 c=0 out=5
+  4  jnz 0  a=5 b=0 c=0
+  0  adv 1  a=2 b=0 c=0
+  2  out a  a=2 b=0 c=0 out=2
+  4  jnz 0  a=2 b=0 c=0
+  0  adv 1  a=1 b=0 c=0
+  2  out a  a=1 b=0 c=0 out=1
+  4  jnz 0  a=1 b=0 c=0
+  0  adv 1  a=0 b=0 c=0
+  2  out a  a=0 b=0 c=0 out=0
+  4  jnz 0  a=0 b=0 c=0
+halt
+"
+    );
+    assert!(trace([1, 0, 0], &[3, 0]).unwrap().ends_with("...\n"));
+    assert!(trace([1, 0, 0], &[2, 7])
+        .unwrap()
+        .ends_with("reserved combo operand 7\n"));
+    // A divided by 2^70
+    assert_eq!(
+        trace([70, 0, 0], &[0, 4, 5, 4]).unwrap(),
+        "      a=70 b=0 c=0\n  0  adv a  a=0 b=0 c=0\n  2  out a  a=0 b=0 c=0 out=0\nhalt\n"
+    );
+}
+#[test]
+fn test_lowest_a() {
+    let real = [2, 4, 1, 7, 7, 5, 0, 3, 4, 4, 1, 7, 5, 5, 3, 0];
+    let mut output = Vec::new();
+    for a in [1, 7, 8, 4242, 0o1234567, 0o7654321076543] {
+        run_program(&real, [a, 0, 0], &mut output, false).unwrap();
+        let lowest = lowest_a(&real, &output).unwrap().unwrap();
+        let reference = lowest_a_with(&real, &output, Interpreter::Reference);
+        assert_eq!(reference, Ok(Some(lowest)));
+        assert!(lowest <= a);
+        let target = output.clone();
+        run_program(&real, [lowest, 0, 0], &mut output, false).unwrap();
+        assert_eq!(output, target, "A = {lowest} instead of {a}");
+    }
+    // bits of A in windows of 3: 5 = 0b101, 2 = 0b10, 1 = 0b1
+    let bits = [2, 4, 5, 5, 0, 1, 3, 0];
+    assert_eq!(lowest_a(&bits, &[5, 2, 1]), Ok(Some(5)));
+    assert_eq!(lowest_a(&bits, &[5, 2, 0]), Ok(None));
+    // A = 0 runs the loop once
+    assert_eq!(lowest_a(&[0, 3, 5, 4, 3, 0], &[0]), Ok(Some(0)));
+    // only zeros
+    assert_eq!(lowest_a(&[0, 1, 5, 0, 3, 0], &[0, 1, 5, 0, 3, 0]), Ok(None));
+    assert_eq!(
+        lowest_a(&[1, 1, 5, 5, 0, 3, 3, 0], &[0]),
+        Err(ParseError::unlocated(
+            "the program is not a loop over the digits of register A: it reads b before writing it"
+        ))
+    );
+    assert_eq!(
+        lowest_a(&real, &[0; 22]),
+        Err(ParseError::unlocated(
+            "the program needs 66 bits of register A to output 22 values, more than 64"
+        ))
+    );
+    assert!(lowest_a(&real, &[0; 21]).is_ok());
+    assert!(lowest_a(&[0, 3, 5, 4, 5, 4, 3, 0], &[0]).is_err());
+    assert!(lowest_a(&[0, 3, 5, 4], &[0]).is_err());
+    assert!(lowest_a(&[0, 5, 5, 4, 3, 0], &[0]).is_err());
+}
+#[test]
+fn test_assemble() {
+    let source = "
+loop:
+    bst a
+    bxl 7
+    cdv b
+    adv 3
+    bxc
+    bxl 7
+    out b
+    jnz loop
+";
+    let real = [2, 4, 1, 7, 7, 5, 0, 3, 4, 0, 1, 7, 5, 5, 3, 0];
+    assert_eq!(assemble(source), Ok(real.to_vec()));
+    let programs: [&[u8]; 4] = [
+        &real,
+        &[0, 1, 5, 4, 3, 0],
+        &[3, 1, 0, 5, 4, 0, 1, 2],
+        &[2, 4, 1, 7, 7, 5, 0, 3, 4, 4, 5, 5, 3, 0],
+    ];
+    for binary in programs {
+        let listing = disassemble(binary).unwrap();
+        assert_eq!(assemble(&listing).as_deref(), Ok(binary));
+        assert_eq!(disassemble(&assemble(&listing).unwrap()), Ok(listing));
+    }
+    assert_eq!(
+        assemble("adv 7"),
+        Err(ParseError::new(1, 5, "reserved combo operand 7"))
+    );
+    assert_eq!(
+        assemble("bst a\nout 4"),
+        Err(ParseError::new(
+            2,
+            5,
+            "expected a combo operand `0`-`3`, `a`, `b` or `c`"
+        ))
+    );
+    assert_eq!(
+        assemble("bxl 8"),
+        Err(ParseError::new(1, 5, "expected a literal operand `0`-`7`"))
+    );
+    assert_eq!(
+        assemble("  mul 2"),
+        Err(ParseError::new(1, 3, "unknown instruction `mul`"))
+    );
+    assert_eq!(
+        assemble("out"),
+        Err(ParseError::new(1, 4, "missing operand"))
+    );
+    assert_eq!(
+        assemble("jnz end\nbxc\nbxc\nbxc\nbxc\nend: out a"),
+        Err(ParseError::new(
+            1,
+            5,
+            "label `end` at address 10 out of reach"
+        ))
+    );
+    assert_eq!(
+        assemble("l: bxc\nl: bxc"),
+        Err(ParseError::new(2, 1, "duplicate label `l`"))
+    );
+}
+#[test]
+fn test_program() {
+    let programs: [&[u8]; 4] = [
+        &[2, 4, 1, 7, 7, 5, 0, 3, 4, 4, 1, 7, 5, 5, 3, 0],
+        &[0, 1, 5, 4, 3, 0],
+        &[2, 4, 5, 5, 0, 1, 3, 0],
+        // jump to the odd address 1, running `bxl 0`, `out a` and `adv 1`
+        &[3, 1, 0, 5, 4, 0, 1, 2],
+    ];
+    let (mut expected, mut output) = (Vec::new(), Vec::new());
+    for binary in programs {
+        let program = Program::new(binary).unwrap();
+        for a in [0, 1, 5, 8, 70, 729, 0o7654321, 1 << 40] {
+            for shortcut in [false, true] {
+                let res = program.run([a, 3, 9], &mut output, shortcut);
+                let expected_res = run_program(binary, [a, 3, 9], &mut expected, shortcut);
+                assert_eq!((res, &output), (expected_res, &expected));
+            }
+        }
+    }
+    // the example of part 2 outputs itself, then one more value with a
+    // higher digit of A
+    let quine = [0, 3, 5, 4, 3, 0];
+    let program = Program::new(&quine).unwrap();
+    for (a, shortcut, res) in [
+        (117440, true, None),
+        (117440, false, None),
+        (0o10345300, true, Some(6)),
+        (0o10345300, false, Some(8)),
+        // same length as the program but not the program
+        (0o355300, false, Some(6)),
+        (0o355300, true, Some(3)),
+    ] {
+        assert_eq!(program.run([a, 0, 0], &mut output, shortcut), Ok(res));
+        assert_eq!(
+            run_program(&quine, [a, 0, 0], &mut expected, shortcut),
+            Ok(res)
+        );
+        assert_eq!(output, expected);
+    }
+    // programs that `parse` rejects
+    let reserved = Err(ParseError::unlocated(
+        "reserved combo operand 7 of `out` at address 2",
+    ));
+    assert_eq!(
+        run_program(&[0, 1, 5, 7, 3, 0], [1, 0, 0], &mut output, false),
+        reserved
+    );
+    assert_eq!(Program::new(&[0, 1, 5, 7, 3, 0]).err(), reserved.err());
+    assert!(Program::new(&[0, 1, 5]).is_err());
+    assert!(run_program(&[0, 9], [1, 0, 0], &mut output, false).is_err());
+    assert!(lowest_a_with(&[2, 7, 5, 4, 3, 0], &[0], Interpreter::Reference).is_err());
+    assert_eq!(run_program(&[], [1, 0, 0], &mut output, false), Ok(None));
+    assert_eq!(
+        Program::new(&[3, 0])
+            .unwrap()
+            .run([1, 0, 0], &mut output, false),
+        run_program(&[3, 0], [1, 0, 0], &mut output, false)
+    );
+}
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8"),
+        Err(ParseError::new(5, 12, "expected a 3-bit value"))
+    );
+    assert_eq!(
+        parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5"),
+        Err(ParseError::new(
+            5,
+            15,
+            "program ends with an opcode without operand"
+        ))
+    );
+    assert_eq!(
+        parse("Register A: 1\nRegister X: 0\nRegister C: 0\n\nProgram: 0,1"),
+        Err(ParseError::new(
+            2,
+            1,
+            "expected `Register B: ` at start of `Register X: 0`"
+        ))
+    );
+    let registers = "Register A: 1\nRegister B: 0\nRegister C: 0\n\n";
+    assert_eq!(
+        parse(&format!("{registers}Program: 0,7,3,0")),
+        Err(ParseError::new(5, 12, "reserved combo operand 7 of `adv`"))
+    );
+    // `jnz 1` runs `adv 7` at the odd address 1
+    assert_eq!(
+        parse(&format!("{registers}Program: 1,0,7,0,3,1")),
+        Err(ParseError::new(5, 14, "reserved combo operand 7 of `adv`"))
+    );
+    // 7 is a literal for `bxl`, and `bst 7` at the odd address 1 never runs
+    assert!(parse(&format!("{registers}Program: 1,7,3,2,7,0")).is_ok());
+    assert!(parse(&format!("{registers}Program: 0,2,7,1,3,0")).is_ok());
+}
@@ -0,0 +1,464 @@
+use std::str::FromStr;
+
+use aoc_core::{parse_token, split_token, Params, ParseError, Solution};
+use aoc_grid::Grid;
+use aoc_search::State;
+
+type Coord = aoc_grid::Coord<i8>;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input<'a> = Memory<'a>;
+    type Answer1 = u32;
+    type Answer2 = String;
+    const PARAMS: &'static [&'static str] = &["size", "bytes", "solver", "falling"];
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let mut memory = Memory::new(input, params.get("size")?, params.get("bytes")?)?;
+        memory.solver = params.get("solver")?.unwrap_or_default();
+        memory.falling = params.get("falling")?.unwrap_or_default();
+        Ok(memory)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        if input.falling {
+            return part1_falling(input);
+        }
+        part1(input)
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        if input.falling {
+            return part2_falling(input).map(|tick| tick.to_string());
+        }
+        part2(input).map(str::to_string)
+    }
+}
+
+fn part1(memory: &Memory) -> Result<u32, ParseError> {
+    let mut g = memory.grid();
+    for &i in memory.walls.iter().take(memory.n_first) {
+        g[i] = b'#';
+    }
+    shortest_path(&g).ok_or_else(|| ParseError::eof(memory.input, "no path to the exit"))
+}
+fn part2<'a>(memory: &Memory<'a>) -> Result<&'a str, ParseError> {
+    let first = match memory.solver {
+        Solver::Bisect => first_blocking_bisect(memory),
+        Solver::UnionFind => first_blocking_union_find(memory),
+    };
+    let Some(first) = first else {
+        return Err(ParseError::eof(memory.input, "no byte cuts the exit off"));
+    };
+    Ok(memory.input.trim().lines().nth(first).unwrap_or_default())
+}
+
+/// Fewest steps to the exit leaving at tick 0 while the bytes fall.
+fn part1_falling(memory: &Memory) -> Result<u32, ParseError> {
+    escape_falling(memory, 0)
+        .ok_or_else(|| ParseError::eof(memory.input, "no escape leaving at tick 0"))
+}
+/// Latest tick to leave at while the bytes fall and still reach the exit.
+fn part2_falling(memory: &Memory) -> Result<usize, ParseError> {
+    let n = memory.walls.len();
+    if escape_falling(memory, 0).is_none() {
+        return Err(ParseError::eof(memory.input, "no escape leaving at tick 0"));
+    }
+    if escape_falling(memory, n).is_some() {
+        return Err(ParseError::eof(memory.input, "escape leaving at any tick"));
+    }
+    // an escape leaving at `lo`, none at `hi`
+    let (mut lo, mut hi) = (0, n);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if escape_falling(memory, mid).is_some() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(lo)
+}
+
+/// Cell of the memory space reached at tick `time`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Tick {
+    cell: usize,
+    time: usize,
+}
+
+impl State for Tick {
+    /// The cells only get corrupted, so reaching one earlier is never worse
+    /// and the search keeps one time per cell: the earliest.
+    fn index(&self) -> usize {
+        self.cell
+    }
+}
+
+/// Fewest steps to the exit leaving the top left corner at tick `departure`,
+/// the `k`-th byte (from 1) landing at tick `k` and each step taking a tick:
+/// a cell can only be stepped on before its byte lands.
+fn escape_falling(memory: &Memory, departure: usize) -> Option<u32> {
+    let g = memory.grid();
+    let mut lands = vec![usize::MAX; g.len()];
+    for (k, &i) in memory.walls.iter().enumerate().rev() {
+        lands[i] = k + 1;
+    }
+    let end = g.len() - 1;
+    if lands[0] <= departure {
+        return None;
+    }
+    let lands = &lands;
+    let neighbors = |cur: Tick| {
+        let time = cur.time + 1;
+        g.neighbors4(g.pos::<i8>(cur.cell))
+            .filter_map(|nei| g.idx(nei))
+            .filter(move |&cell| lands[cell] > time)
+            .map(move |cell| Tick { cell, time })
+    };
+    let start = Tick {
+        cell: 0,
+        time: departure,
+    };
+    aoc_search::bfs(g.len(), [start], neighbors, |t| t.cell == end).best()
+}
+
+/// How part 2 finds the first byte cutting the exit off.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Solver {
+    /// Binary search over the number of fallen bytes, with a search for a
+    /// path at each step.
+    #[default]
+    Bisect,
+    /// Bytes removed from the last one, merging the free cells around them
+    /// until the corners are connected.
+    UnionFind,
+}
+
+impl FromStr for Solver {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bisect" => Ok(Solver::Bisect),
+            "union-find" => Ok(Solver::UnionFind),
+            _ => Err("expected `bisect` or `union-find`".to_string()),
+        }
+    }
+}
+
+/// Index of the first byte after which there is no path to the exit.
+fn first_blocking_bisect(memory: &Memory) -> Option<usize> {
+    let walls = &memory.walls;
+    let mut g = memory.grid();
+    // a path with `lo` bytes fallen, none with `hi` (`walls.len() + 1`
+    // standing for never)
+    let (mut lo, mut hi) = (0, walls.len() + 1);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if has_path(&mut g, &walls[..mid]) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (hi <= walls.len()).then(|| hi - 1)
+}
+/// Same as [`first_blocking_bisect`] in near linear time: the bytes are
+/// removed in reverse and each cell they free is merged with its free
+/// neighbors, until the corners are in the same set.
+fn first_blocking_union_find(memory: &Memory) -> Option<usize> {
+    let g = memory.grid();
+    let (start, end) = (0, g.len() - 1);
+    // the cells are free once their first byte is removed
+    let mut first = vec![usize::MAX; g.len()];
+    for (k, &i) in memory.walls.iter().enumerate().rev() {
+        first[i] = k;
+    }
+    let is_free = |i: usize, fallen: usize| i == start || first[i] >= fallen;
+    let mut sets = UnionFind::new(g.len());
+    let merge_around = |sets: &mut UnionFind, i: usize, fallen: usize| {
+        for nei in g.neighbors4(g.pos::<i8>(i)).filter_map(|nei| g.idx(nei)) {
+            if is_free(nei, fallen) {
+                sets.union(i, nei);
+            }
+        }
+    };
+    let n = memory.walls.len();
+    for i in (0..g.len()).filter(|&i| is_free(i, n)) {
+        merge_around(&mut sets, i, n);
+    }
+    if is_free(end, n) && sets.find(start) == sets.find(end) {
+        return None;
+    }
+    for (k, &i) in memory.walls.iter().enumerate().rev() {
+        if first[i] != k {
+            continue;
+        }
+        merge_around(&mut sets, i, k);
+        if is_free(end, k) && sets.find(start) == sets.find(end) {
+            return Some(k);
+        }
+    }
+    None
+}
+
+/// Disjoint sets of cells, with path halving and union by size.
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
+
+/// Falling bytes in a memory space of `size`x`size` cells, of which the
+/// first `n_first` are used by part 1.
+pub struct Memory<'a> {
+    input: &'a str,
+    size: i8,
+    n_first: usize,
+    /// Indices of the falling bytes in the memory grid.
+    walls: Vec<usize>,
+    solver: Solver,
+    /// Whether the bytes fall while walking to the exit.
+    falling: bool,
+}
+
+impl<'a> Memory<'a> {
+    /// Without parameters the size is inferred from the bytes furthest away
+    /// and part 1 uses the first 12 bytes of a 7x7 memory space (like the
+    /// example) or 1024 bytes otherwise.
+    fn new(input: &'a str, size: Option<i8>, n_first: Option<usize>) -> Result<Self, ParseError> {
+        let mut bytes = Vec::new();
+        for line in input.trim().lines() {
+            let (x, y) = split_token(input, line, ",")?;
+            let x: i8 = parse_token(input, x)?;
+            let y: i8 = parse_token(input, y)?;
+            bytes.push((line, Coord::new(x, y)));
+        }
+        let size = match size {
+            Some(size) => size,
+            None => {
+                let mut size = 0;
+                for &(line, c) in &bytes {
+                    let Some(fits) = c.x().max(c.y()).checked_add(1) else {
+                        let msg = format!("byte outside of the largest {0}x{0} memory", i8::MAX);
+                        return Err(ParseError::at(input, line, msg));
+                    };
+                    size = size.max(fits);
+                }
+                size
+            }
+        };
+        if size <= 0 {
+            return Err(ParseError::eof(
+                input,
+                "cannot tell the size of the memory space",
+            ));
+        }
+        let n_first = n_first.unwrap_or(if size == 7 { 12 } else { 1024 });
+        let mut memory = Memory {
+            input,
+            size,
+            n_first,
+            walls: Vec::with_capacity(bytes.len()),
+            solver: Solver::default(),
+            falling: false,
+        };
+        let g = memory.grid();
+        for (line, coord) in bytes {
+            let i = g.idx(coord).ok_or_else(|| {
+                ParseError::at(input, line, format!("byte outside of {size}x{size} memory"))
+            })?;
+            memory.walls.push(i);
+        }
+        Ok(memory)
+    }
+    fn grid(&self) -> Grid<u8> {
+        Grid::new(self.size as usize, self.size as usize, b'.')
+    }
+}
+
+fn has_path(g: &mut Grid<u8>, walls: &[usize]) -> bool {
+    for &i in walls {
+        g[i] = b'#';
+    }
+    let res = shortest_path(g);
+    for &i in walls {
+        g[i] = b'.';
+    }
+    res.is_some()
+}
+/// Fewest steps from the top left corner to the bottom right one.
+fn shortest_path(g: &Grid<u8>) -> Option<u32> {
+    let (nx, end) = (g.nx(), g.len() - 1);
+    // on indices rather than coordinates, as the bisection of part 2 runs
+    // this search many times
+    let neighbors = |i: usize| {
+        let (x, y) = (i % nx, i / nx);
+        let neighbors = [
+            (x > 0).then(|| i - 1),
+            (x + 1 < nx).then(|| i + 1),
+            (y > 0).then(|| i - nx),
+            (i + nx < g.len()).then(|| i + nx),
+        ];
+        neighbors
+            .into_iter()
+            .flatten()
+            .filter(|&nei| g[nei] != b'#')
+    };
+    aoc_search::bfs_cost(g.len(), [0], neighbors, |i| i == end)
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+#[test]
+fn test1() {
+    let memory = Day18::parse(EXAMPLE).unwrap();
+    assert_eq!((memory.size, memory.n_first), (7, 12));
+    assert_eq!(part1(&memory), Ok(22));
+    assert_eq!(
+        part1(&Memory::new("0,1\n1,0\n6,6", Some(7), None).unwrap()),
+        Err(ParseError::new(3, 4, "no path to the exit"))
+    );
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Day18::parse(EXAMPLE).unwrap()), Ok("6,1"));
+    let params = "size=6,bytes=20".parse().unwrap();
+    assert_eq!(
+        Day18::parse_with(EXAMPLE, &params).err(),
+        Some(ParseError::new(7, 1, "byte outside of 6x6 memory"))
+    );
+    assert_eq!(
+        Day18::parse("1,2\n127,127").err(),
+        Some(ParseError::new(
+            2,
+            1,
+            "byte outside of the largest 127x127 memory"
+        ))
+    );
+    assert!(Day18::parse("1,2\n126,126").is_ok());
+}
+#[test]
+fn test_solvers() {
+    let bisect = Day18::parse(EXAMPLE).unwrap();
+    let union_find = Day18::parse_with(EXAMPLE, &"solver=union-find".parse().unwrap()).unwrap();
+    assert_eq!(union_find.solver, Solver::UnionFind);
+    assert_eq!(part2(&union_find), Ok("6,1"));
+    for n in 0..EXAMPLE.trim().lines().count() {
+        let input = EXAMPLE
+            .trim()
+            .lines()
+            .take(n)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let memory = Memory::new(&input, Some(7), None).unwrap();
+        let expected = first_blocking_bisect(&memory);
+        assert_eq!(first_blocking_union_find(&memory), expected);
+        assert_eq!(expected.is_some(), n > 20);
+    }
+    assert_eq!(
+        part2(&Memory::new("1,1\n2,2", Some(7), None).unwrap()),
+        Err(ParseError::new(2, 4, "no byte cuts the exit off"))
+    );
+    assert_eq!(first_blocking_bisect(&bisect), Some(20));
+}
+
+/// Fewest steps to the exit with the time in the state, waiting allowed.
+#[cfg(test)]
+fn escape_falling_brute_force(memory: &Memory, departure: usize) -> Option<u32> {
+    let g = memory.grid();
+    let lands = |cell: usize| {
+        let k = memory.walls.iter().position(|&i| i == cell);
+        k.map_or(usize::MAX, |k| k + 1)
+    };
+    if lands(0) <= departure {
+        return None;
+    }
+    let mut reached = vec![0];
+    for time in departure..departure + 4 * g.len() {
+        if reached.contains(&(g.len() - 1)) {
+            return Some((time - departure) as u32);
+        }
+        let mut next = Vec::new();
+        for &cell in &reached {
+            let neighbors = g.neighbors4(g.pos::<i8>(cell)).filter_map(|nei| g.idx(nei));
+            for nei in neighbors.chain([cell]) {
+                if lands(nei) > time + 1 && !next.contains(&nei) {
+                    next.push(nei);
+                }
+            }
+        }
+        reached = next;
+    }
+    None
+}
+
+#[test]
+fn test_falling() {
+    let params = "falling=true".parse().unwrap();
+    let memory = Day18::parse_with(EXAMPLE, &params).unwrap();
+    for departure in 0..=memory.walls.len() {
+        assert_eq!(
+            escape_falling(&memory, departure),
+            escape_falling_brute_force(&memory, departure),
+            "leaving at {departure}"
+        );
+    }
+    assert_eq!(Day18::part1(&memory), Ok(12));
+    assert_eq!(Day18::part2(&memory), Ok("9".to_string()));
+}
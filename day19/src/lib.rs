@@ -0,0 +1,118 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{split_token, ParseError, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input<'a> = Onsen<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Onsen::new(input)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(onsen: &Onsen) -> u64 {
+    part_x(onsen, true)
+}
+fn part2(onsen: &Onsen) -> u64 {
+    part_x(onsen, false)
+}
+fn part_x(onsen: &Onsen, part1: bool) -> u64 {
+    let len_max_pattern = onsen
+        .patterns
+        .iter()
+        .map(|x| x.len())
+        .max()
+        .unwrap_or_default();
+    let mut lru = HashMap::new();
+    onsen
+        .designs
+        .iter()
+        .map(|x| {
+            let res = made_with_patterns(x, &onsen.patterns, len_max_pattern, &mut lru);
+            if part1 {
+                (res > 0) as u64
+            } else {
+                res
+            }
+        })
+        .sum()
+}
+
+/// Available towel patterns and the designs to make with them.
+pub struct Onsen<'a> {
+    patterns: HashSet<&'a str>,
+    designs: Vec<&'a str>,
+}
+
+impl<'a> Onsen<'a> {
+    fn new(input: &'a str) -> Result<Self, ParseError> {
+        // the designs may be missing, but not the blank line before them
+        let (patterns, designs) = split_token(input, input.trim_start(), "\n\n")?;
+        Ok(Onsen {
+            patterns: patterns.split(", ").collect(),
+            designs: designs.trim().lines().collect(),
+        })
+    }
+}
+
+fn made_with_patterns<'a>(
+    x: &'a str,
+    patterns: &HashSet<&str>,
+    len_max_pattern: usize,
+    lru: &mut HashMap<&'a str, u64>,
+) -> u64 {
+    if let Some(&v) = lru.get(x) {
+        return v;
+    }
+    let mut total = patterns.contains(x) as u64;
+    for i in 1..x.len().min(len_max_pattern + 1) {
+        let (left, right) = x.split_at(i);
+        if patterns.contains(left) {
+            let n_right = made_with_patterns(right, patterns, len_max_pattern, lru);
+            total += n_right;
+        }
+    }
+    lru.insert(x, total);
+    total
+}
+#[cfg(test)]
+const EXAMPLE: &str = "
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+#[test]
+fn test1() {
+    assert_eq!(part1(&Onsen::new(EXAMPLE).unwrap()), 6);
+}
+#[test]
+fn test2() {
+    assert_eq!(part2(&Onsen::new(EXAMPLE).unwrap()), 16);
+}
+#[test]
+fn test_parse() {
+    let onsen = Onsen::new("a, b\n\n\n").unwrap();
+    assert_eq!((onsen.patterns.len(), onsen.designs.len()), (2, 0));
+    assert_eq!(
+        Onsen::new("a, b\nab\n").err(),
+        Some(ParseError::new(1, 1, "expected `\\n\\n` in `a, b\\nab\\n`"))
+    );
+}
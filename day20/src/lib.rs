@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::Grid;
+
+type Coord = aoc_grid::Coord<i16>;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input<'a> = Racetrack;
+    type Answer1 = u32;
+    type Answer2 = u32;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Racetrack::new(input)
+    }
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError> {
+        Ok(part1::<100>(input))
+    }
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError> {
+        Ok(part2::<100>(input))
+    }
+}
+
+fn part1<const N: u32>(g: &Racetrack) -> u32 {
+    g.dijsktra_cheat::<N>(&g.path, 2)
+}
+fn part2<const N: u32>(g: &Racetrack) -> u32 {
+    g.dijsktra_cheat::<N>(&g.path, 20)
+}
+
+pub struct Racetrack {
+    grid: Grid<u8>,
+    start: usize,
+    end: usize,
+    /// Indices of the cells of the track without cheating, from start to end.
+    path: Vec<usize>,
+}
+impl Racetrack {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, |c| b"#.SE".contains(&c).then_some(c))?;
+        if let Some(c) = grid.border().find(|&c: &Coord| grid[c] != b'#') {
+            let [x, y] = c.0.map(|x| x as usize);
+            let line = input.trim().lines().nth(y).unwrap_or_default();
+            return Err(ParseError::at(
+                input,
+                &line[x..],
+                "racetrack not closed by walls",
+            ));
+        }
+        let find = |tile: u8| {
+            grid.iter()
+                .position(|&x| x == tile)
+                .ok_or_else(|| ParseError::eof(input, format!("missing tile `{}`", tile as char)))
+        };
+        let mut g = Racetrack {
+            start: find(b'S')?,
+            end: find(b'E')?,
+            grid,
+            path: Vec::new(),
+        };
+        g.path = g
+            .track()
+            .ok_or_else(|| ParseError::eof(input, "no track from `S` to `E`"))?;
+        Ok(g)
+    }
+    fn idx(&self, coord: &Coord) -> usize {
+        let idx = self.grid.idx(*coord);
+        debug_assert!(idx.is_some());
+        idx.unwrap_or_default()
+    }
+    fn pos(&self, idx: usize) -> Coord {
+        self.grid.pos(idx)
+    }
+    fn cheat_neighbors(
+        &self,
+        cheat_duration: i16,
+        cur: Coord,
+    ) -> impl Iterator<Item = (Coord, usize)> + '_ {
+        ((-cheat_duration)..=cheat_duration).flat_map(move |ix| {
+            ((-cheat_duration)..=cheat_duration).filter_map(move |iy| {
+                let dist = ix.abs() + iy.abs();
+                let nei = cur + Coord::new(ix, iy);
+                if !(1..=cheat_duration).contains(&dist) {
+                    return None;
+                }
+                let idx = self.grid.idx(nei)?;
+                (self.grid[idx] != b'#').then_some((nei, idx))
+            })
+        })
+    }
+    /// Cells next to `idx` off the walls.
+    fn neighbors(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        let cur = self.pos(idx);
+        Coord::DIRECTIONS
+            .into_iter()
+            .map(move |dir| self.idx(&(cur + dir)))
+            .filter(|&nei| self.grid[nei] != b'#')
+    }
+    /// Shortest track from start to end.
+    fn track(&self) -> Option<Vec<usize>> {
+        let search = aoc_search::bfs(
+            self.grid.len(),
+            [self.start],
+            |i| self.neighbors(i),
+            |i| i == self.end,
+        );
+        search.path(self.end)
+    }
+    fn dijsktra_cheat<const N: u32>(&self, base_path: &[usize], cheat_duration: i16) -> u32 {
+        let mut cheats = HashSet::new();
+        let mut table = vec![None; self.grid.len()];
+        for (i, &c) in base_path.iter().enumerate() {
+            table[c] = Some(i);
+        }
+        for (i, &cur) in base_path.iter().enumerate() {
+            let cur_pos = self.pos(cur);
+            for (nei_pos, nei) in self.cheat_neighbors(cheat_duration, cur_pos) {
+                let dist = nei_pos.manhattan(cur_pos);
+                let i2 = table[nei];
+                if i2.is_some_and(|i2| i2.saturating_sub(i) >= dist + N as usize) {
+                    cheats.insert([cur_pos, nei_pos]);
+                }
+            }
+        }
+        cheats.len() as u32
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+#[test]
+fn test1() {
+    let total = 14 + 14 + 2 + 4 + 2 + 3 + 1 + 1 + 1 + 1 + 1;
+    assert_eq!(part1::<2>(&Racetrack::new(EXAMPLE).unwrap()), total);
+}
+#[test]
+fn test2() {
+    let total = 32 + 31 + 29 + 39 + 25 + 23 + 20 + 19 + 12 + 14 + 12 + 22 + 4 + 3;
+    assert_eq!(part2::<50>(&Racetrack::new(EXAMPLE).unwrap()), total);
+}
+
+/// Track of a random `width` x `height` maze (2 cells at least) between two
+/// of its cells: the maze being a spanning tree, the track has no branch like
+/// the puzzle ones.
+#[cfg(test)]
+fn random_racetrack(width: usize, height: usize, mut seed: u64) -> String {
+    let mut rand = move |n: usize| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize % n
+    };
+    let (w, h) = (2 * width + 1, 2 * height + 1);
+    let mut open = vec![false; w * h];
+    let mut stack = vec![w + 1];
+    open[w + 1] = true;
+    while let Some(&cur) = stack.last() {
+        let (x, y) = (cur % w, cur / w);
+        let next = [(0, -2), (2, 0), (0, 2), (-2, 0)]
+            .into_iter()
+            .map(|(dx, dy)| (x as isize + dx, y as isize + dy))
+            .filter(|&(x, y)| x > 0 && y > 0 && x < w as isize && y < h as isize)
+            .map(|(x, y)| y as usize * w + x as usize)
+            .filter(|&i| !open[i])
+            .collect::<Vec<_>>();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let nei = next[rand(next.len())];
+        open[nei] = true;
+        open[(cur + nei) / 2] = true;
+        stack.push(nei);
+    }
+    let cell = |i: usize| (2 * (i / width) + 1) * w + 2 * (i % width) + 1;
+    let n_cells = width * height;
+    let first = rand(n_cells);
+    // the end among the other cells, or `E` would replace `S`
+    let (start, end) = (cell(first), cell((first + 1 + rand(n_cells - 1)) % n_cells));
+    let mut prev = vec![usize::MAX; w * h];
+    let mut queue = std::collections::VecDeque::from([start]);
+    prev[start] = start;
+    while let Some(cur) = queue.pop_front() {
+        for nei in [cur - w, cur + 1, cur + w, cur - 1] {
+            if open[nei] && prev[nei] == usize::MAX {
+                prev[nei] = cur;
+                queue.push_back(nei);
+            }
+        }
+    }
+    let mut tiles = vec![b'#'; w * h];
+    let mut cur = end;
+    while cur != start {
+        tiles[cur] = b'.';
+        cur = prev[cur];
+    }
+    tiles[start] = b'S';
+    tiles[end] = b'E';
+    tiles
+        .chunks(w)
+        .map(|line| String::from_utf8_lossy(line) + "\n")
+        .collect()
+}
+
+/// Cheats counted from the distances to the start and to the end of every
+/// cell, without the path.
+#[cfg(test)]
+fn cheats_brute_force(g: &Racetrack, min_saving: usize, cheat_duration: usize) -> u32 {
+    let distances = |from: usize| {
+        let search = aoc_search::bfs(g.grid.len(), [from], |i| g.neighbors(i), |_| false);
+        (0..g.grid.len())
+            .map(|i| search.dist(i).map_or(usize::MAX, |d| d as usize))
+            .collect::<Vec<_>>()
+    };
+    let (from_start, to_end) = (distances(g.start), distances(g.end));
+    let track = (0..g.grid.len())
+        .filter(|&i| from_start[i] != usize::MAX)
+        .collect::<Vec<_>>();
+    let mut count = 0;
+    for &p in &track {
+        for &q in &track {
+            let d = g.pos(p).manhattan(g.pos(q));
+            if (1..=cheat_duration).contains(&d)
+                && to_end[q] != usize::MAX
+                && from_start[p] + d + to_end[q] + min_saving <= from_start[g.end]
+            {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_cheats(width in 2..8usize, height in 1..8usize, seed: u64, cheat_duration in 1..8i16) {
+        let g = Racetrack::new(&random_racetrack(width, height, seed)).unwrap();
+        let duration = cheat_duration as usize;
+        proptest::prop_assert_eq!(
+            g.dijsktra_cheat::<1>(&g.path, cheat_duration),
+            cheats_brute_force(&g, 1, duration)
+        );
+        proptest::prop_assert_eq!(
+            g.dijsktra_cheat::<6>(&g.path, cheat_duration),
+            cheats_brute_force(&g, 6, duration)
+        );
+    }
+}